@@ -5,5 +5,7 @@ pub fn fmt(gid: &GID) -> String {
         GID::Int32(i) => format!("{}", i),
         GID::String(s) => s.to_string(),
         GID::Int64(i) => format!("{}", i),
+        GID::Uuid(u) => u.to_string(),
+        GID::Custom(_) => gid.to_string(),
     }
 }
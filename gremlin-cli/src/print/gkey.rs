@@ -1,8 +1,26 @@
+use super::{edge, vertex};
 use gremlin_client::GKey;
 
 pub fn fmt(gkey: &GKey) -> String {
     match gkey {
         GKey::String(s) => s.to_string(),
-        _ => todo!(),
+        GKey::Token(t) => t.value().to_string(),
+        GKey::Vertex(v) => vertex::fmt(v),
+        GKey::Edge(e) => edge::fmt(e),
+        GKey::T(t) => t.to_string(),
+        GKey::Direction(d) => d.to_string(),
+        GKey::Int32(n) => n.to_string(),
+        GKey::Int64(n) => n.to_string(),
+        GKey::Float(n) => n.to_string(),
+        GKey::Double(n) => n.to_string(),
+        GKey::Bool(b) => b.to_string(),
+        GKey::Uuid(u) => u.to_string(),
+        GKey::Date(d) => d.to_string(),
+        GKey::List(l) => format!(
+            "[{}]",
+            l.iter().map(fmt).collect::<Vec<String>>().join(", ")
+        ),
+        GKey::Property(p) => p.to_string(),
+        GKey::VertexProperty(p) => p.to_string(),
     }
 }
@@ -16,6 +16,7 @@ pub use remote::AsyncTerminator;
 pin_project! {
     pub struct RemoteTraversalStream<T> {
         phantom: PhantomData<T>,
+        current: Option<Traverser>,
         #[pin]
         stream: GResultSet,
     }
@@ -25,6 +26,7 @@ impl<T> RemoteTraversalStream<T> {
     fn new(stream: GResultSet) -> Self {
         RemoteTraversalStream {
             phantom: PhantomData,
+            current: None,
             stream,
         }
     }
@@ -33,15 +35,23 @@ impl<T: FromGValue> Stream for RemoteTraversalStream<T> {
     type Item = GremlinResult<T>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+        let mut this = self.project();
 
-        let item = futures::ready!(this.stream.poll_next(cx));
+        loop {
+            if let Some(traverser) = this.current.take() {
+                if let Some((value, rest)) = traverser.split() {
+                    *this.current = rest;
+                    return Poll::Ready(Some(value.take::<T>()));
+                }
+            }
 
-        Poll::Ready(item.map(|e| {
-            e.expect("Failed to take an item from the result set")
-                .take::<Traverser>()
-                .expect("Failed to convert the item to a Traverser")
-                .take::<T>()
-        }))
+            let item = futures::ready!(this.stream.as_mut().poll_next(cx));
+
+            match item.map(|e| e.and_then(|e| e.take::<Traverser>())) {
+                Some(Ok(traverser)) => *this.current = Some(traverser),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
     }
 }
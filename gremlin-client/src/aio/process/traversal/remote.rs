@@ -2,19 +2,56 @@ use crate::aio::process::traversal::RemoteTraversalStream;
 use crate::aio::GremlinClient;
 use crate::conversion::FromGValue;
 use crate::process::traversal::remote::Terminator;
-use crate::process::traversal::GraphTraversal;
-use crate::GremlinResult;
+use crate::process::traversal::strategies::EXPLAIN_SCRIPT;
+use crate::process::traversal::{Bytecode, GraphTraversal, TraversalStrategies, TraversalStrategy};
+use crate::structure::TraversalExplanation;
+use crate::{GremlinError, GremlinResult};
 use futures::future::{BoxFuture, FutureExt};
 use futures::StreamExt;
 
 #[derive(Clone)]
 pub struct AsyncTerminator {
     client: GremlinClient,
+    strategies: TraversalStrategies,
 }
 
 impl AsyncTerminator {
     pub fn new(client: GremlinClient) -> AsyncTerminator {
-        AsyncTerminator { client }
+        AsyncTerminator {
+            client,
+            strategies: TraversalStrategies::new(),
+        }
+    }
+
+    pub fn with_strategy<S>(mut self, strategy: S) -> AsyncTerminator
+    where
+        S: TraversalStrategy + 'static,
+    {
+        self.strategies.add_strategy(strategy);
+        self
+    }
+
+    pub(crate) fn explain(
+        &self,
+        bytecode: &Bytecode,
+    ) -> BoxFuture<'static, GremlinResult<TraversalExplanation>> {
+        let client = self.client.clone();
+        let bytecode = self.strategies.apply(bytecode);
+
+        async move {
+            let bytecode = bytecode?;
+            client
+                .execute(EXPLAIN_SCRIPT, &[("bytecode", &bytecode)])
+                .await?
+                .next()
+                .await
+                .transpose()?
+                .ok_or_else(|| {
+                    GremlinError::Generic(String::from("Missing traversal explanation"))
+                })?
+                .take::<TraversalExplanation>()
+        }
+        .boxed()
     }
 }
 
@@ -51,11 +88,7 @@ impl<T: FromGValue + std::marker::Send + 'static> Terminator<T> for AsyncTermina
         async move {
             let mut stream = iter.await?;
 
-            let mut vec = vec![];
-            while let Some(item) = stream.next().await {
-                vec.push(item?);
-            }
-            Ok(vec.pop())
+            stream.next().await.transpose()
         }
         .boxed()
     }
@@ -69,11 +102,7 @@ impl<T: FromGValue + std::marker::Send + 'static> Terminator<T> for AsyncTermina
         async move {
             let mut stream = iter.await?;
 
-            let mut vec = vec![];
-            while let Some(item) = stream.next().await {
-                vec.push(item?);
-            }
-            Ok(vec.len() > 0)
+            Ok(stream.next().await.transpose()?.is_some())
         }
         .boxed()
     }
@@ -83,10 +112,10 @@ impl<T: FromGValue + std::marker::Send + 'static> Terminator<T> for AsyncTermina
         E: Terminator<T>,
     {
         let client = self.client.clone();
-        let bytecode = traversal.bytecode().clone();
+        let bytecode = self.strategies.apply(traversal.bytecode());
 
         async move {
-            let stream = client.submit_traversal(&bytecode).await?;
+            let stream = client.submit_traversal(&bytecode?).await?;
 
             Ok(RemoteTraversalStream::new(stream))
         }
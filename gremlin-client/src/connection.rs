@@ -45,6 +45,7 @@ impl ConnectionStream {
 #[derive(Debug)]
 pub(crate) struct Connection {
     stream: ConnectionStream,
+    broken: bool,
 }
 
 impl Into<ConnectionOptions> for (&str, u16) {
@@ -176,6 +177,7 @@ impl Connection {
     {
         Ok(Connection {
             stream: ConnectionStream::connect(options.into())?,
+            broken: false,
         })
     }
 
@@ -186,6 +188,16 @@ impl Connection {
     pub fn recv(&mut self) -> GremlinResult<Vec<u8>> {
         self.stream.recv()
     }
+
+    /// Mark the connection as unusable, e.g. when a response stream was abandoned
+    /// and unread frames are still pending on the socket.
+    pub(crate) fn set_broken(&mut self) {
+        self.broken = true;
+    }
+
+    pub(crate) fn is_broken(&self) -> bool {
+        self.broken
+    }
 }
 
 impl TlsOptions {
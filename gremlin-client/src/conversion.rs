@@ -1,6 +1,6 @@
 use crate::{
     process::traversal::Bytecode,
-    structure::{TextP, P as Predicate},
+    structure::{Binding, Custom, Direction, Lambda, TextP, Tree, P as Predicate},
     Edge, GKey, GValue, GremlinError, GremlinResult, IntermediateRepr, List, Map, Metric, Path,
     Property, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty, GID,
 };
@@ -8,7 +8,9 @@ use crate::{
 use crate::structure::Set;
 use crate::structure::Traverser;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::convert::TryFrom;
+use std::hash::Hash;
 
 pub trait ToGValue: Send + Sync {
     fn to_gvalue(&self) -> GValue;
@@ -23,9 +25,24 @@ impl Into<Params> for () {
     }
 }
 
-impl ToGValue for Vec<GValue> {
+impl ToGValue for GValue {
     fn to_gvalue(&self) -> GValue {
-        GValue::List(List::new(self.clone()))
+        self.clone()
+    }
+}
+
+impl<T: ToGValue> ToGValue for Vec<T> {
+    fn to_gvalue(&self) -> GValue {
+        GValue::List(List::new(self.iter().map(ToGValue::to_gvalue).collect()))
+    }
+}
+
+impl<T: ToGValue> ToGValue for Option<T> {
+    fn to_gvalue(&self) -> GValue {
+        match self {
+            Some(value) => value.to_gvalue(),
+            None => GValue::Null,
+        }
     }
 }
 
@@ -35,6 +52,8 @@ impl ToGValue for GID {
             GID::Int32(n) => GValue::from(*n),
             GID::Int64(n) => GValue::from(*n),
             GID::String(n) => GValue::from(n),
+            GID::Uuid(n) => GValue::from(*n),
+            GID::Custom(n) => (**n).clone(),
         }
     }
 }
@@ -75,6 +94,18 @@ impl ToGValue for TextP {
     }
 }
 
+impl ToGValue for Binding {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Binding(self.clone())
+    }
+}
+
+impl ToGValue for Lambda {
+    fn to_gvalue(&self) -> GValue {
+        GValue::Lambda(self.clone())
+    }
+}
+
 impl ToGValue for String {
     fn to_gvalue(&self) -> GValue {
         GValue::String(self.clone())
@@ -122,9 +153,7 @@ impl_from_gvalue!(Edge, GValue::Edge);
 impl_from_gvalue!(Path, GValue::Path);
 impl_from_gvalue!(String, GValue::String);
 impl_from_gvalue!(f32, GValue::Float);
-impl_from_gvalue!(f64, GValue::Double);
 impl_from_gvalue!(i32, GValue::Int32);
-impl_from_gvalue!(i64, GValue::Int64);
 impl_from_gvalue!(bool, GValue::Bool);
 impl_from_gvalue!(uuid::Uuid, GValue::Uuid);
 impl_from_gvalue!(Metric, GValue::Metric);
@@ -133,6 +162,73 @@ impl_from_gvalue!(TraversalExplanation, GValue::TraversalExplanation);
 impl_from_gvalue!(IntermediateRepr, GValue::IntermediateRepr);
 impl_from_gvalue!(chrono::DateTime<chrono::Utc>, GValue::Date);
 impl_from_gvalue!(Traverser, GValue::Traverser);
+impl_from_gvalue!(Lambda, GValue::Lambda);
+impl_from_gvalue!(Binding, GValue::Binding);
+impl_from_gvalue!(Direction, GValue::Direction);
+impl_from_gvalue!(Custom, GValue::Custom);
+impl_from_gvalue!(Tree, GValue::Tree);
+
+// Integers up to 2^53 are exactly representable as f64
+const MAX_EXACT_F64_INT: i64 = 1 << 53;
+
+impl FromGValue for i64 {
+    fn from_gvalue(v: GValue) -> GremlinResult<i64> {
+        match v {
+            GValue::Int64(n) => Ok(n),
+            GValue::Int32(n) => Ok(n.into()),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to {}",
+                v, "i64"
+            ))),
+        }
+    }
+}
+
+impl FromGValue for f64 {
+    fn from_gvalue(v: GValue) -> GremlinResult<f64> {
+        match v {
+            GValue::Double(n) => Ok(n),
+            GValue::Float(n) => Ok(n.into()),
+            GValue::Int32(n) => Ok(n.into()),
+            GValue::Int64(n) if (-MAX_EXACT_F64_INT..=MAX_EXACT_F64_INT).contains(&n) => {
+                Ok(n as f64)
+            }
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to {}",
+                v, "f64"
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_from_gvalue_unsigned {
+    ($t:ty) => {
+        impl FromGValue for $t {
+            fn from_gvalue(v: GValue) -> GremlinResult<$t> {
+                let n = match v {
+                    GValue::Int32(n) => i64::from(n),
+                    GValue::Int64(n) => n,
+                    _ => {
+                        return Err(GremlinError::Cast(format!(
+                            "Cannot convert {:?} to {}",
+                            v,
+                            stringify!($t)
+                        )))
+                    }
+                };
+                <$t>::try_from(n).map_err(|_| {
+                    GremlinError::Cast(format!("{} is out of range for {}", n, stringify!($t)))
+                })
+            }
+        }
+    };
+}
+
+impl_from_gvalue_unsigned!(u8);
+impl_from_gvalue_unsigned!(u16);
+impl_from_gvalue_unsigned!(u32);
+impl_from_gvalue_unsigned!(u64);
+impl_from_gvalue_unsigned!(usize);
 
 impl FromGValue for GKey {
     fn from_gvalue(v: GValue) -> GremlinResult<GKey> {
@@ -141,6 +237,23 @@ impl FromGValue for GKey {
             GValue::Token(s) => Ok(GKey::String(s.value().clone())),
             GValue::Vertex(s) => Ok(GKey::Vertex(s)),
             GValue::Edge(s) => Ok(GKey::Edge(s)),
+            GValue::T(s) => Ok(GKey::T(s)),
+            GValue::Direction(s) => Ok(GKey::Direction(s)),
+            GValue::Int32(n) => Ok(GKey::Int32(n)),
+            GValue::Int64(n) => Ok(GKey::Int64(n)),
+            GValue::Float(n) => Ok(GKey::Float(n)),
+            GValue::Double(n) => Ok(GKey::Double(n)),
+            GValue::Bool(b) => Ok(GKey::Bool(b)),
+            GValue::Uuid(u) => Ok(GKey::Uuid(u)),
+            GValue::Date(d) => Ok(GKey::Date(d)),
+            GValue::List(l) => Ok(GKey::List(
+                l.into_iter()
+                    .map(GKey::from_gvalue)
+                    .collect::<GremlinResult<Vec<_>>>()?,
+            )),
+            GValue::Property(p) => Ok(GKey::Property(p)),
+            GValue::VertexProperty(p) => Ok(GKey::VertexProperty(p)),
+            // Map keys are not supported, see GKey
             _ => Err(GremlinError::Cast(format!(
                 "Cannot convert {:?} to {}",
                 v, "GKey"
@@ -154,24 +267,120 @@ impl FromGValue for GValue {
         Ok(v)
     }
 }
-// Borrow from GValue
+
+// Null is not an empty container, Option<C> reads it as None
+fn from_elements<T, C>(v: GValue, target: &str) -> GremlinResult<C>
+where
+    T: FromGValue,
+    C: std::iter::FromIterator<T>,
+{
+    match v {
+        GValue::List(l) => l.into_iter().map(T::from_gvalue).collect(),
+        GValue::Set(s) => s.into_iter().map(T::from_gvalue).collect(),
+        _ => Err(GremlinError::Cast(format!(
+            "Cannot convert {:?} to {}",
+            v, target
+        ))),
+    }
+}
+
+fn from_entries<K, V, C>(v: GValue, target: &str) -> GremlinResult<C>
+where
+    K: FromGValue,
+    V: FromGValue,
+    C: std::iter::FromIterator<(K, V)>,
+{
+    match v {
+        GValue::Map(m) => m
+            .into_iter()
+            .map(|(k, v)| Ok((K::from_gvalue(k.into())?, V::from_gvalue(v)?)))
+            .collect(),
+        _ => Err(GremlinError::Cast(format!(
+            "Cannot convert {:?} to {}",
+            v, target
+        ))),
+    }
+}
 
 impl<T: FromGValue> FromGValue for Vec<T> {
     fn from_gvalue(v: GValue) -> GremlinResult<Vec<T>> {
+        from_elements(v, "Vec")
+    }
+}
+
+impl<T: FromGValue> FromGValue for VecDeque<T> {
+    fn from_gvalue(v: GValue) -> GremlinResult<VecDeque<T>> {
+        from_elements(v, "VecDeque")
+    }
+}
+
+impl<T: FromGValue + Hash + Eq> FromGValue for HashSet<T> {
+    fn from_gvalue(v: GValue) -> GremlinResult<HashSet<T>> {
+        from_elements(v, "HashSet")
+    }
+}
+
+impl<T: FromGValue + Ord> FromGValue for BTreeSet<T> {
+    fn from_gvalue(v: GValue) -> GremlinResult<BTreeSet<T>> {
+        from_elements(v, "BTreeSet")
+    }
+}
+
+impl<K: FromGValue + Hash + Eq, V: FromGValue> FromGValue for HashMap<K, V> {
+    fn from_gvalue(v: GValue) -> GremlinResult<HashMap<K, V>> {
+        from_entries(v, "HashMap")
+    }
+}
+
+impl<K: FromGValue + Ord, V: FromGValue> FromGValue for BTreeMap<K, V> {
+    fn from_gvalue(v: GValue) -> GremlinResult<BTreeMap<K, V>> {
+        from_entries(v, "BTreeMap")
+    }
+}
+
+impl<T: FromGValue> FromGValue for Option<T> {
+    fn from_gvalue(v: GValue) -> GremlinResult<Option<T>> {
         match v {
-            GValue::List(l) => {
-                let results: GremlinResult<Vec<T>> =
-                    l.take().into_iter().map(T::from_gvalue).collect();
-                Ok(results?)
-            }
-            _ => Err(GremlinError::Cast(format!(
-                "Cannot convert {:?} to List of T",
-                v
-            ))),
+            GValue::Null => Ok(None),
+            v => T::from_gvalue(v).map(Some),
         }
     }
 }
 
+impl<T: FromGValue> FromGValue for Box<T> {
+    fn from_gvalue(v: GValue) -> GremlinResult<Box<T>> {
+        T::from_gvalue(v).map(Box::new)
+    }
+}
+
+macro_rules! impl_from_gvalue_tuple {
+    ($len:expr, $($t:ident),+) => {
+        impl<$($t: FromGValue),+> FromGValue for ($($t,)+) {
+            fn from_gvalue(v: GValue) -> GremlinResult<($($t,)+)> {
+                match v {
+                    GValue::List(l) if l.len() == $len => {
+                        let mut elements = l.into_iter();
+                        Ok(($($t::from_gvalue(elements.next().unwrap_or(GValue::Null))?,)+))
+                    }
+                    _ => Err(GremlinError::Cast(format!(
+                        "Cannot convert {:?} to a tuple of {} elements",
+                        v, $len
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_from_gvalue_tuple!(1, A);
+impl_from_gvalue_tuple!(2, A, B);
+impl_from_gvalue_tuple!(3, A, B, C);
+impl_from_gvalue_tuple!(4, A, B, C, D);
+impl_from_gvalue_tuple!(5, A, B, C, D, E);
+impl_from_gvalue_tuple!(6, A, B, C, D, E, F);
+
+// Borrow from GValue
+
 #[doc(hidden)]
 pub trait BorrowFromGValue: Sized {
     fn from_gvalue<'a>(v: &'a GValue) -> GremlinResult<&'a Self>;
@@ -211,6 +420,11 @@ impl_borrow_from_gvalue!(i64, GValue::Int64);
 impl_borrow_from_gvalue!(uuid::Uuid, GValue::Uuid);
 impl_borrow_from_gvalue!(chrono::DateTime<chrono::Utc>, GValue::Date);
 impl_borrow_from_gvalue!(bool, GValue::Bool);
+impl_borrow_from_gvalue!(Lambda, GValue::Lambda);
+impl_borrow_from_gvalue!(Binding, GValue::Binding);
+impl_borrow_from_gvalue!(Direction, GValue::Direction);
+impl_borrow_from_gvalue!(Custom, GValue::Custom);
+impl_borrow_from_gvalue!(Tree, GValue::Tree);
 
 #[test]
 fn to_gvalue_for_vec_gvalue() {
@@ -220,3 +434,113 @@ fn to_gvalue_for_vec_gvalue() {
     let expected = GValue::List(List::new(converted_ids));
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn from_gvalue_for_maps() {
+    let mut counts = HashMap::new();
+    counts.insert(GKey::from("person"), GValue::Int64(4));
+    counts.insert(GKey::from("software"), GValue::Int64(2));
+
+    let value = GValue::Map(Map::from(counts));
+
+    let expected: HashMap<String, i64> =
+        vec![(String::from("person"), 4), (String::from("software"), 2)]
+            .into_iter()
+            .collect();
+    assert_eq!(
+        expected,
+        value.clone().take::<HashMap<String, i64>>().unwrap()
+    );
+
+    let ordered = value.take::<BTreeMap<String, i64>>().unwrap();
+    assert_eq!(
+        vec!["person", "software"],
+        ordered.keys().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn from_gvalue_for_collections_and_options() {
+    let list = GValue::List(List::new(vec![
+        GValue::Int32(1),
+        GValue::Int32(2),
+        GValue::Int32(1),
+    ]));
+
+    assert_eq!(vec![1, 2, 1], list.clone().take::<Vec<i32>>().unwrap());
+    assert_eq!(
+        VecDeque::from(vec![1, 2, 1]),
+        list.clone().take::<VecDeque<i32>>().unwrap()
+    );
+    assert_eq!(2, list.clone().take::<HashSet<i32>>().unwrap().len());
+    assert_eq!(
+        vec![1, 2],
+        list.take::<BTreeSet<i32>>()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(None, GValue::Null.take::<Option<String>>().unwrap());
+    assert_eq!(
+        Some(String::from("marko")),
+        GValue::from("marko").take::<Option<String>>().unwrap()
+    );
+    assert_eq!(Box::new(1), GValue::Int32(1).take::<Box<i32>>().unwrap());
+    assert!(GValue::Int32(1).take::<Option<String>>().is_err());
+}
+
+#[test]
+fn from_gvalue_for_containers_rejects_null() {
+    assert!(GValue::Null.take::<Vec<i32>>().is_err());
+    assert!(GValue::Null.take::<VecDeque<i32>>().is_err());
+    assert!(GValue::Null.take::<HashSet<i32>>().is_err());
+    assert!(GValue::Null.take::<BTreeSet<i32>>().is_err());
+    assert!(GValue::Null.take::<HashMap<String, i32>>().is_err());
+    assert!(GValue::Null.take::<BTreeMap<String, i32>>().is_err());
+
+    assert_eq!(None, GValue::Null.take::<Option<Vec<i32>>>().unwrap());
+    assert_eq!(None, GValue::Null.take::<Option<HashSet<i32>>>().unwrap());
+}
+
+#[test]
+fn from_gvalue_for_tuples() {
+    let pair = GValue::List(List::new(vec![GValue::from("marko"), GValue::Int64(29)]));
+
+    assert_eq!(
+        (String::from("marko"), 29),
+        pair.clone().take::<(String, i64)>().unwrap()
+    );
+    assert!(pair.take::<(String, i64, bool)>().is_err());
+}
+
+#[test]
+fn from_gvalue_widens_numbers() {
+    assert_eq!(3i64, GValue::Int32(3).take::<i64>().unwrap());
+    assert_eq!(1.5f64, GValue::Float(1.5).take::<f64>().unwrap());
+    assert_eq!(3f64, GValue::Int32(3).take::<f64>().unwrap());
+    assert_eq!(
+        (1i64 << 53) as f64,
+        GValue::Int64(1 << 53).take::<f64>().unwrap()
+    );
+    assert!(GValue::Int64((1 << 53) + 1).take::<f64>().is_err());
+    assert!(GValue::Int64(3).take::<i32>().is_err());
+
+    assert_eq!(3i64, i64::try_from(GValue::Int32(3)).unwrap());
+    assert_eq!(2.5f64, f64::try_from(GValue::Float(2.5)).unwrap());
+}
+
+#[test]
+fn from_gvalue_for_unsigned() {
+    assert_eq!(255u8, GValue::Int32(255).take::<u8>().unwrap());
+    assert_eq!(10u64, GValue::Int64(10).take::<u64>().unwrap());
+    assert_eq!(10usize, usize::try_from(GValue::Int32(10)).unwrap());
+    assert_eq!(
+        Some(4u32),
+        Option::<u32>::try_from(GValue::Int64(4)).unwrap()
+    );
+
+    assert!(GValue::Int32(256).take::<u8>().is_err());
+    assert!(GValue::Int64(-1).take::<u64>().is_err());
+    assert!(GValue::Double(1.0).take::<u64>().is_err());
+}
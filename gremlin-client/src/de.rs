@@ -0,0 +1,560 @@
+use crate::structure::{Edge, GKey, GValue, Vertex, GID, T};
+use crate::{GremlinError, GremlinResult};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use std::fmt::Display;
+use std::vec::IntoIter;
+
+/// Deserialize any [GValue](enum.GValue.html) into a type implementing `serde::Deserialize`.
+///
+/// Vertices are read as maps of `id`, `label` and their properties, where each property
+/// is the list of its values. Single-element lists are unwrapped when the target is not a
+/// sequence, so `value_map()` results map straight onto plain struct fields.
+pub fn from_gvalue<T>(value: GValue) -> GremlinResult<T>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(Deserializer(value))
+}
+
+impl de::Error for GremlinError {
+    fn custom<M: Display>(msg: M) -> Self {
+        GremlinError::Cast(msg.to_string())
+    }
+}
+
+struct Deserializer(GValue);
+
+impl Deserializer {
+    // Properties and value maps wrap every value in a list, unwrap it for scalar targets.
+    fn unwrap_single(self) -> Self {
+        match self.0 {
+            GValue::List(list) if list.len() == 1 => Deserializer(list.take().remove(0)),
+            GValue::Set(set) if set.iter().count() == 1 => Deserializer(set.take().remove(0)),
+            value => Deserializer(value),
+        }
+    }
+}
+
+macro_rules! deserialize_scalar {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> GremlinResult<V::Value>
+            where
+                V: Visitor<'de>,
+            {
+                self.unwrap_single().deserialize_any(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = GremlinError;
+
+    fn deserialize_any<V>(self, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            GValue::Null => visitor.visit_unit(),
+            GValue::Bool(v) => visitor.visit_bool(v),
+            GValue::Int32(v) => visitor.visit_i32(v),
+            GValue::Int64(v) => visitor.visit_i64(v),
+            GValue::Float(v) => visitor.visit_f32(v),
+            GValue::Double(v) => visitor.visit_f64(v),
+            GValue::String(v) => visitor.visit_string(v),
+            GValue::Uuid(v) => visitor.visit_string(v.to_string()),
+            GValue::Date(v) => visitor.visit_string(v.to_rfc3339()),
+            GValue::Token(v) => visitor.visit_string(v.value().clone()),
+            GValue::T(v) => visitor.visit_str(t_name(&v)),
+            GValue::Direction(v) => visitor.visit_string(v.to_string()),
+            GValue::List(list) => visit_seq(list.take(), visitor),
+            GValue::Set(set) => visit_seq(set.take(), visitor),
+            GValue::Path(path) => visit_seq(path.objects().clone().take(), visitor),
+            GValue::Map(map) => visit_map(
+                map.into_iter()
+                    .map(|(k, v)| (key_to_gvalue(k), v))
+                    .collect(),
+                visitor,
+            ),
+            GValue::Vertex(vertex) => visit_map(vertex_entries(vertex), visitor),
+            GValue::Edge(edge) => visit_map(edge_entries(edge), visitor),
+            GValue::VertexProperty(property) => Deserializer(property.value().clone())
+                .unwrap_single()
+                .deserialize_any(visitor),
+            GValue::Property(property) => Deserializer(property.value().clone())
+                .unwrap_single()
+                .deserialize_any(visitor),
+            GValue::Traverser(traverser) => Deserializer(traverser.get().clone())
+                .unwrap_single()
+                .deserialize_any(visitor),
+            other => Err(GremlinError::Cast(format!(
+                "Cannot deserialize {:?} with serde",
+                other
+            ))),
+        }
+    }
+
+    deserialize_scalar! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_f32
+        deserialize_f64 deserialize_char deserialize_str deserialize_string deserialize_bytes
+        deserialize_byte_buf deserialize_identifier deserialize_map
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            GValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.unwrap_single().deserialize_any(visitor)
+    }
+
+    // Unit variants are plain strings, other variants a map with a single entry keyed by
+    // the variant name.
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.unwrap_single().0 {
+            GValue::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            GValue::Map(map) => {
+                let mut entries = map.into_iter();
+                match (entries.next(), entries.next()) {
+                    (Some((key, value)), None) => visitor.visit_enum(Enum {
+                        variant: key_to_gvalue(key),
+                        value,
+                    }),
+                    _ => Err(GremlinError::Cast(String::from(
+                        "Expected a map with a single entry for an enum variant",
+                    ))),
+                }
+            }
+            other => Err(GremlinError::Cast(format!(
+                "Cannot deserialize {:?} into an enum",
+                other
+            ))),
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+fn visit_seq<'de, V>(values: Vec<GValue>, visitor: V) -> GremlinResult<V::Value>
+where
+    V: Visitor<'de>,
+{
+    let len = values.len();
+    let mut seq = Seq(values.into_iter());
+    let value = visitor.visit_seq(&mut seq)?;
+
+    match seq.0.len() {
+        0 => Ok(value),
+        remaining => Err(de::Error::invalid_length(
+            len - remaining,
+            &"fewer elements in the list",
+        )),
+    }
+}
+
+fn visit_map<'de, V>(entries: Vec<(GValue, GValue)>, visitor: V) -> GremlinResult<V::Value>
+where
+    V: Visitor<'de>,
+{
+    visitor.visit_map(Entries {
+        entries: entries.into_iter(),
+        value: None,
+    })
+}
+
+fn key_to_gvalue(key: GKey) -> GValue {
+    match key {
+        GKey::String(s) => GValue::String(s),
+        GKey::Token(t) => GValue::String(t.value().clone()),
+        GKey::T(t) => GValue::String(String::from(t_name(&t))),
+        GKey::Direction(d) => GValue::String(d.to_string()),
+        GKey::Vertex(v) => GValue::Vertex(v),
+        GKey::Edge(e) => GValue::Edge(e),
+        other => GValue::from(other),
+    }
+}
+
+fn t_name(t: &T) -> &'static str {
+    match t {
+        T::Id => "id",
+        T::Key => "key",
+        T::Label => "label",
+        T::Value => "value",
+    }
+}
+
+fn gid_to_gvalue(id: &GID) -> GValue {
+    match id {
+        GID::String(s) => GValue::String(s.clone()),
+        GID::Int32(i) => GValue::Int32(*i),
+        GID::Int64(i) => GValue::Int64(*i),
+        GID::Uuid(u) => GValue::Uuid(*u),
+        GID::Custom(v) => (**v).clone(),
+    }
+}
+
+fn vertex_entries(vertex: Vertex) -> Vec<(GValue, GValue)> {
+    let mut entries = vec![
+        ("id".into(), gid_to_gvalue(vertex.id())),
+        ("label".into(), vertex.label().clone().into()),
+    ];
+
+    entries.extend(vertex.into_iter().map(|(key, properties)| {
+        let values = properties.into_iter().map(|p| p.value().clone()).collect();
+        (
+            GValue::String(key),
+            GValue::List(crate::structure::List::new(values)),
+        )
+    }));
+    entries
+}
+
+fn edge_entries(edge: Edge) -> Vec<(GValue, GValue)> {
+    let mut entries = vec![
+        ("id".into(), gid_to_gvalue(edge.id())),
+        ("label".into(), edge.label().clone().into()),
+        ("inV".into(), gid_to_gvalue(edge.in_v().id())),
+        ("outV".into(), gid_to_gvalue(edge.out_v().id())),
+    ];
+
+    entries.extend(
+        edge.into_iter()
+            .map(|(key, property)| (GValue::String(key), property.value().clone())),
+    );
+    entries
+}
+
+struct Seq(IntoIter<GValue>);
+
+impl<'de> SeqAccess<'de> for Seq {
+    type Error = GremlinError;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> GremlinResult<Option<S::Value>>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(Deserializer(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct Entries {
+    entries: IntoIter<(GValue, GValue)>,
+    value: Option<GValue>,
+}
+
+impl<'de> MapAccess<'de> for Entries {
+    type Error = GremlinError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> GremlinResult<Option<K::Value>>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(Deserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> GremlinResult<S::Value>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer(value)),
+            None => Err(GremlinError::Cast(String::from(
+                "Map value requested before its key",
+            ))),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct Enum {
+    variant: GValue,
+    value: GValue,
+}
+
+impl<'de> EnumAccess<'de> for Enum {
+    type Error = GremlinError;
+    type Variant = Variant;
+
+    fn variant_seed<S>(self, seed: S) -> GremlinResult<(S::Value, Variant)>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(Deserializer(self.variant))?;
+        Ok((variant, Variant(self.value)))
+    }
+}
+
+struct Variant(GValue);
+
+impl<'de> VariantAccess<'de> for Variant {
+    type Error = GremlinError;
+
+    fn unit_variant(self) -> GremlinResult<()> {
+        match self.0 {
+            GValue::Null => Ok(()),
+            other => Err(GremlinError::Cast(format!(
+                "Expected a unit variant, found {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> GremlinResult<S::Value>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        seed.deserialize(Deserializer(self.0))
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(Deserializer(self.0), visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> GremlinResult<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(Deserializer(self.0), visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_gvalue;
+    use crate::structure::{GKey, GValue, List, Map, Vertex, VertexProperty, T};
+    use crate::{to_gvalue, GraphSON};
+    use serde_derive::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Person {
+        name: String,
+        age: i64,
+        nicknames: Vec<String>,
+        email: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    enum Role {
+        Admin,
+        Member { since: i32 },
+    }
+
+    fn list(values: Vec<GValue>) -> GValue {
+        GValue::List(List::new(values))
+    }
+
+    #[test]
+    fn value_map_into_struct() {
+        let mut map: HashMap<GKey, GValue> = HashMap::new();
+        map.insert(T::Id.into(), 1.into());
+        map.insert("name".into(), list(vec!["marko".into()]));
+        map.insert("age".into(), list(vec![29.into()]));
+        map.insert("nicknames".into(), list(vec!["okram".into(), "mr".into()]));
+
+        let person: Person = from_gvalue(Map::from(map).into()).unwrap();
+
+        assert_eq!(
+            Person {
+                name: String::from("marko"),
+                age: 29,
+                nicknames: vec![String::from("okram"), String::from("mr")],
+                email: None,
+            },
+            person
+        );
+    }
+
+    #[test]
+    fn vertex_into_struct() {
+        let vertex = Vertex::builder(1, "person")
+            .property(VertexProperty::new(2, "name", "marko"))
+            .property(VertexProperty::new(3, "age", 29))
+            .property(VertexProperty::new(4, "nicknames", "okram"))
+            .property(VertexProperty::new(5, "email", "marko@example.com"))
+            .build();
+
+        let person: Person = from_gvalue(vertex.into()).unwrap();
+
+        assert_eq!(
+            Person {
+                name: String::from("marko"),
+                age: 29,
+                nicknames: vec![String::from("okram")],
+                email: Some(String::from("marko@example.com")),
+            },
+            person
+        );
+
+        let software: serde_json::Value =
+            from_gvalue(Vertex::builder(7, "software").build().into()).unwrap();
+
+        assert_eq!(json!({"id": 7, "label": "software"}), software);
+    }
+
+    #[test]
+    fn enums_round_trip() {
+        let admin = to_gvalue(&Role::Admin).unwrap();
+        assert_eq!(GValue::from("Admin"), admin);
+        assert_eq!(Role::Admin, from_gvalue(admin).unwrap());
+
+        let member = to_gvalue(&Role::Member { since: 2019 }).unwrap();
+        assert_eq!(Role::Member { since: 2019 }, from_gvalue(member).unwrap());
+    }
+
+    #[test]
+    fn struct_round_trip() {
+        let person = Person {
+            name: String::from("josh"),
+            age: 32,
+            nicknames: vec![],
+            email: None,
+        };
+
+        let value = to_gvalue(&person).unwrap();
+        let map = value.get::<Map>().unwrap();
+
+        assert_eq!(Some(&GValue::from("josh")), map.get("name"));
+        assert_eq!(Some(&GValue::Int64(32)), map.get("age"));
+        assert_eq!(Some(&GValue::Null), map.get("email"));
+
+        assert_eq!(person, from_gvalue(value).unwrap());
+    }
+
+    #[test]
+    fn serialized_struct_is_writable() {
+        let person = Person {
+            name: String::from("josh"),
+            age: 32,
+            nicknames: vec![],
+            email: None,
+        };
+
+        let value = to_gvalue(&person).unwrap();
+
+        let v1 = GraphSON::V1.write(&value).unwrap();
+        assert_eq!(json!("josh"), v1["name"]);
+        assert_eq!(serde_json::Value::Null, v1["email"]);
+
+        let v2 = GraphSON::V2.write(&value).unwrap();
+        assert_eq!(serde_json::Value::Null, v2["email"]);
+
+        let v3 = GraphSON::V3.write(&value).unwrap();
+        let entries = v3["@value"].as_array().unwrap();
+        let email = entries.iter().position(|e| e == "email").unwrap();
+        assert_eq!(serde_json::Value::Null, entries[email + 1]);
+    }
+
+    #[test]
+    fn wrong_type_is_an_error() {
+        assert!(from_gvalue::<i32>(GValue::from("marko")).is_err());
+        assert!(from_gvalue::<Person>(list(vec![1.into(), 2.into()])).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::structure::{Cardinality, Edge, GValue, Property, Vertex, VertexProperty, GID};
+use crate::{GremlinError, GremlinResult};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// An in-memory property graph, shared by all the traversals spawned from
+/// [with_embedded](../process/traversal/struct.RemoteTraversalSource.html#method.with_embedded).
+///
+/// Elements without a user supplied id get `i64` ids, as TinkerGraph does. Lookups are
+/// linear, the graph is meant for tests and small data sets.
+#[derive(Clone, Default)]
+pub struct TinkerGraph {
+    data: Arc<Mutex<GraphData>>,
+}
+
+impl TinkerGraph {
+    pub fn new() -> TinkerGraph {
+        TinkerGraph::default()
+    }
+
+    /// A copy of the vertices, in insertion order
+    pub fn vertices(&self) -> Vec<Vertex> {
+        self.lock().vertices.clone()
+    }
+
+    /// A copy of the edges, in insertion order
+    pub fn edges(&self) -> Vec<Edge> {
+        self.lock().edges.clone()
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, GraphData> {
+        // a panic while evaluating a traversal leaves the data consistent step by step
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl fmt::Debug for TinkerGraph {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = self.lock();
+        write!(
+            f,
+            "tinkergraph[vertices:{} edges:{}]",
+            data.vertices.len(),
+            data.edges.len()
+        )
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct GraphData {
+    vertices: Vec<Vertex>,
+    edges: Vec<Edge>,
+    next_id: i64,
+}
+
+impl GraphData {
+    pub(crate) fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    pub(crate) fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub(crate) fn vertex(&self, id: &GID) -> Option<&Vertex> {
+        self.vertices.iter().find(|v| v.id() == id)
+    }
+
+    pub(crate) fn edge(&self, id: &GID) -> Option<&Edge> {
+        self.edges.iter().find(|e| e.id() == id)
+    }
+
+    fn vertex_mut(&mut self, id: &GID) -> GremlinResult<&mut Vertex> {
+        self.vertices
+            .iter_mut()
+            .find(|v| v.id() == id)
+            .ok_or_else(|| GremlinError::Generic(format!("Vertex {:?} does not exist", id)))
+    }
+
+    fn edge_mut(&mut self, id: &GID) -> GremlinResult<&mut Edge> {
+        self.edges
+            .iter_mut()
+            .find(|e| e.id() == id)
+            .ok_or_else(|| GremlinError::Generic(format!("Edge {:?} does not exist", id)))
+    }
+
+    fn next_id(&mut self) -> GID {
+        loop {
+            self.next_id += 1;
+            let id = GID::Int64(self.next_id);
+            if self.vertex(&id).is_none() && self.edge(&id).is_none() {
+                return id;
+            }
+        }
+    }
+
+    pub(crate) fn add_vertex(&mut self, id: Option<GID>, label: &str) -> GremlinResult<Vertex> {
+        let id = match id {
+            Some(id) if self.vertex(&id).is_some() => {
+                return Err(GremlinError::Generic(format!(
+                    "Vertex with id {:?} already exists",
+                    id
+                )))
+            }
+            Some(id) => id,
+            None => self.next_id(),
+        };
+        let vertex = Vertex::new(id, label, HashMap::new());
+        self.vertices.push(vertex.clone());
+        Ok(vertex)
+    }
+
+    pub(crate) fn add_edge(
+        &mut self,
+        id: Option<GID>,
+        label: &str,
+        out_v: &GID,
+        in_v: &GID,
+    ) -> GremlinResult<Edge> {
+        let id = match id {
+            Some(id) if self.edge(&id).is_some() => {
+                return Err(GremlinError::Generic(format!(
+                    "Edge with id {:?} already exists",
+                    id
+                )))
+            }
+            Some(id) => id,
+            None => self.next_id(),
+        };
+        let out_label = self.vertex_mut(out_v)?.label().clone();
+        let in_label = self.vertex_mut(in_v)?.label().clone();
+        let edge = Edge::new(
+            id,
+            String::from(label),
+            in_v.clone(),
+            in_label,
+            out_v.clone(),
+            out_label,
+            HashMap::new(),
+        );
+        self.edges.push(edge.clone());
+        Ok(edge)
+    }
+
+    pub(crate) fn set_vertex_property(
+        &mut self,
+        id: &GID,
+        cardinality: Cardinality,
+        key: &str,
+        value: GValue,
+    ) -> GremlinResult<()> {
+        let property_id = self.next_id();
+        let vertex = self.vertex_mut(id)?;
+        if cardinality == Cardinality::Single {
+            vertex.remove_property(key);
+        }
+        if cardinality == Cardinality::Set
+            && vertex.properties(key).iter().any(|p| p.value() == &value)
+        {
+            return Ok(());
+        }
+        vertex.add_property(VertexProperty::new(property_id, key, value));
+        Ok(())
+    }
+
+    pub(crate) fn set_edge_property(
+        &mut self,
+        id: &GID,
+        key: &str,
+        value: GValue,
+    ) -> GremlinResult<()> {
+        self.edge_mut(id)?.add_property(Property::new(key, value));
+        Ok(())
+    }
+
+    /// Changes the id or the label of a vertex without edges, e.g. `addV().property(T.id, 1)`
+    pub(crate) fn replace_vertex(
+        &mut self,
+        id: &GID,
+        new_id: GID,
+        label: &str,
+    ) -> GremlinResult<Vertex> {
+        if &new_id != id && self.vertex(&new_id).is_some() {
+            return Err(GremlinError::Generic(format!(
+                "Vertex with id {:?} already exists",
+                new_id
+            )));
+        }
+        if self
+            .edges
+            .iter()
+            .any(|e| e.out_v().id() == id || e.in_v().id() == id)
+        {
+            return Err(GremlinError::Generic(format!(
+                "Cannot change the id or the label of vertex {:?} which has edges",
+                id
+            )));
+        }
+        let vertex = self.vertex_mut(id)?;
+        let properties = vertex.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        *vertex = Vertex::new(new_id, label, properties);
+        Ok(vertex.clone())
+    }
+
+    pub(crate) fn replace_edge(
+        &mut self,
+        id: &GID,
+        new_id: GID,
+        label: &str,
+    ) -> GremlinResult<Edge> {
+        if &new_id != id && self.edge(&new_id).is_some() {
+            return Err(GremlinError::Generic(format!(
+                "Edge with id {:?} already exists",
+                new_id
+            )));
+        }
+        let edge = self.edge_mut(id)?;
+        let mut replaced = Edge::new(
+            new_id,
+            String::from(label),
+            edge.in_v().id().clone(),
+            edge.in_v().label().clone(),
+            edge.out_v().id().clone(),
+            edge.out_v().label().clone(),
+            HashMap::new(),
+        );
+        for (_, property) in edge.iter() {
+            replaced.add_property(property.clone());
+        }
+        *edge = replaced;
+        Ok(edge.clone())
+    }
+
+    pub(crate) fn remove_vertex(&mut self, id: &GID) {
+        self.vertices.retain(|v| v.id() != id);
+        self.edges
+            .retain(|e| e.out_v().id() != id && e.in_v().id() != id);
+    }
+
+    pub(crate) fn remove_edge(&mut self, id: &GID) {
+        self.edges.retain(|e| e.id() != id);
+    }
+
+    pub(crate) fn remove_vertex_property(&mut self, property: &VertexProperty) {
+        for vertex in self.vertices.iter_mut() {
+            if let Some(values) = vertex.properties_mut(property.label()) {
+                values.retain(|p| p.id() != property.id());
+                if values.is_empty() {
+                    vertex.remove_property(property.label());
+                }
+            }
+        }
+    }
+
+    pub(crate) fn remove_edge_property(&mut self, edge: &GID, key: &str) -> GremlinResult<()> {
+        self.edge_mut(edge)?.remove_property(key);
+        Ok(())
+    }
+}
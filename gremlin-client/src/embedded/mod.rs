@@ -0,0 +1,36 @@
+//! An in-process graph to run traversals without a Gremlin server.
+//!
+//! A [TinkerGraph](struct.TinkerGraph.html) keeps vertices, edges and their properties in
+//! memory and the traversals built with
+//! [with_embedded](../process/traversal/struct.RemoteTraversalSource.html#method.with_embedded)
+//! are evaluated against it, which is handy in unit tests.
+//!
+//! The core steps are supported: `V`, `E`, `addV`, `addE`, `property`, `drop`, the navigation
+//! and filter steps, `repeat`, `choose`, `coalesce`, `union`, `where`, `select`, `project`,
+//! `order`, `group`, `groupCount`, `limit`, `range`, `count`, `fold` and friends. Other steps,
+//! e.g. `match` or `math`, return a [GremlinError::Generic](../enum.GremlinError.html).
+//!
+//! ```rust
+//! use gremlin_client::embedded::TinkerGraph;
+//! use gremlin_client::process::traversal::traversal;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let g = traversal().with_embedded(TinkerGraph::new());
+//!
+//!     g.add_v("person").property("name", "marko").as_("m")
+//!         .add_v("person").property("name", "vadas")
+//!         .add_e("knows").from("m")
+//!         .to_list()?;
+//!
+//!     let names = g.v(()).has(("name", "marko")).out("knows").values("name").to_list()?;
+//!     assert_eq!(1, names.len());
+//!     Ok(())
+//! }
+//! ```
+
+mod graph;
+mod terminator;
+mod traversal;
+
+pub use graph::TinkerGraph;
+pub use terminator::{EmbeddedIterator, EmbeddedTerminator};
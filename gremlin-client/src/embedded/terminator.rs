@@ -0,0 +1,80 @@
+use crate::conversion::FromGValue;
+use crate::embedded::graph::TinkerGraph;
+use crate::embedded::traversal::Evaluator;
+use crate::process::traversal::{Bytecode, GraphTraversal, Terminator};
+use crate::structure::GValue;
+use crate::GremlinResult;
+use std::marker::PhantomData;
+
+/// Evaluates traversals against a [TinkerGraph](struct.TinkerGraph.html) instead of sending
+/// them to a server.
+#[derive(Clone)]
+pub struct EmbeddedTerminator {
+    graph: TinkerGraph,
+}
+
+impl EmbeddedTerminator {
+    pub fn new(graph: TinkerGraph) -> EmbeddedTerminator {
+        EmbeddedTerminator { graph }
+    }
+
+    pub fn graph(&self) -> &TinkerGraph {
+        &self.graph
+    }
+
+    fn submit(&self, bytecode: &Bytecode) -> GremlinResult<Vec<GValue>> {
+        Evaluator::new(&mut self.graph.lock()).evaluate(bytecode)
+    }
+}
+
+impl<T: FromGValue> Terminator<T> for EmbeddedTerminator {
+    type List = GremlinResult<Vec<T>>;
+    type Next = GremlinResult<Option<T>>;
+    type HasNext = GremlinResult<bool>;
+    type Iter = GremlinResult<EmbeddedIterator<T>>;
+
+    fn to_list<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::List
+    where
+        E: Terminator<T>,
+    {
+        self.iter(traversal)?.collect()
+    }
+
+    fn next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Next
+    where
+        E: Terminator<T>,
+    {
+        self.iter(traversal)?.next().transpose()
+    }
+
+    fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
+    where
+        E: Terminator<T>,
+    {
+        Ok(!self.submit(traversal.bytecode())?.is_empty())
+    }
+
+    fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
+    where
+        E: Terminator<T>,
+    {
+        Ok(EmbeddedIterator {
+            results: self.submit(traversal.bytecode())?.into_iter(),
+            data: PhantomData,
+        })
+    }
+}
+
+/// The results of a traversal evaluated by an [EmbeddedTerminator](struct.EmbeddedTerminator.html)
+pub struct EmbeddedIterator<T: FromGValue> {
+    results: std::vec::IntoIter<GValue>,
+    data: PhantomData<T>,
+}
+
+impl<T: FromGValue> Iterator for EmbeddedIterator<T> {
+    type Item = GremlinResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.results.next().map(T::from_gvalue)
+    }
+}
@@ -0,0 +1,1931 @@
+use crate::conversion::{FromGValue, ToGValue};
+use crate::embedded::graph::GraphData;
+use crate::process::traversal::{Bytecode, Instruction, Operator, Order, Pick, Scope};
+use crate::structure::{
+    Cardinality, Column, Direction, Edge, GKey, GValue, List, Path, Pop, Vertex, GID, P, T,
+};
+use crate::{GremlinError, GremlinResult};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// Steps whose result is a single value computed out of all their input, e.g. the values of a
+// group when the modulating traversal ends with one of them
+const REDUCING: &[&str] = &[
+    "count",
+    "sum",
+    "max",
+    "min",
+    "mean",
+    "fold",
+    "group",
+    "groupCount",
+    "cap",
+];
+
+#[derive(Debug, Clone)]
+struct Traverser {
+    value: GValue,
+    path: Vec<(Vec<String>, GValue)>,
+    loops: Vec<i64>,
+}
+
+impl Traverser {
+    fn root() -> Traverser {
+        Traverser {
+            value: GValue::Null,
+            path: vec![],
+            loops: vec![],
+        }
+    }
+
+    // the output of a reducing step does not keep the history of its input
+    fn fresh(value: GValue) -> Traverser {
+        Traverser {
+            path: vec![(vec![], value.clone())],
+            value,
+            loops: vec![],
+        }
+    }
+
+    fn split(&self, value: GValue) -> Traverser {
+        let mut path = self.path.clone();
+        path.push((vec![], value.clone()));
+        Traverser {
+            value,
+            path,
+            loops: self.loops.clone(),
+        }
+    }
+
+    fn leave(mut self) -> Traverser {
+        self.loops.pop();
+        self
+    }
+}
+
+// A step with the modulators that follow it, `until()`, `emit()` and `times()` can also be
+// given ahead of a `repeat()`
+struct Step<'a> {
+    operator: &'a str,
+    args: &'a [GValue],
+    modulators: Vec<&'a Instruction>,
+    before: Vec<&'a Instruction>,
+}
+
+impl<'a> Step<'a> {
+    fn modulators(&self, operator: &str) -> Vec<&'a Instruction> {
+        self.modulators
+            .iter()
+            .filter(|i| i.operator() == operator)
+            .copied()
+            .collect()
+    }
+
+    fn by(&self, index: usize) -> Option<&'a Instruction> {
+        let by = self.modulators("by");
+        if by.is_empty() {
+            None
+        } else {
+            Some(by[index % by.len()])
+        }
+    }
+}
+
+fn steps(bytecode: &Bytecode) -> Vec<Step<'_>> {
+    let mut steps: Vec<Step> = vec![];
+    let mut pending = vec![];
+    for instruction in bytecode.steps() {
+        let operator = instruction.operator().as_str();
+        let after_repeat = steps.last().map(|s| s.operator == "repeat") == Some(true);
+        match operator {
+            "until" | "emit" | "times" if !after_repeat => pending.push(instruction),
+            "by" | "from" | "to" | "with" | "option" | "until" | "emit" | "times" => {
+                if let Some(step) = steps.last_mut() {
+                    step.modulators.push(instruction)
+                }
+            }
+            _ => steps.push(Step {
+                operator,
+                args: instruction.args(),
+                modulators: vec![],
+                before: std::mem::take(&mut pending),
+            }),
+        }
+    }
+    steps
+}
+
+/// Runs bytecode against the data of a [TinkerGraph](struct.TinkerGraph.html), one step at a
+/// time over all the traversers.
+pub(crate) struct Evaluator<'g> {
+    graph: &'g mut GraphData,
+    side_effects: HashMap<String, GValue>,
+}
+
+impl<'g> Evaluator<'g> {
+    pub(crate) fn new(graph: &'g mut GraphData) -> Evaluator<'g> {
+        Evaluator {
+            graph,
+            side_effects: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn evaluate(&mut self, bytecode: &Bytecode) -> GremlinResult<Vec<GValue>> {
+        for source in bytecode.sources() {
+            match (source.operator().as_str(), source.args().as_slice()) {
+                ("withSideEffect", [GValue::String(key), value]) => {
+                    self.side_effects.insert(key.clone(), value.clone());
+                }
+                (operator, _) => return Err(unsupported(operator)),
+            }
+        }
+        let traversers = self.run(bytecode, vec![Traverser::root()])?;
+        Ok(traversers
+            .into_iter()
+            .map(|t| self.current(t.value))
+            .collect())
+    }
+
+    fn run(&mut self, bytecode: &Bytecode, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let mut traversers = input;
+        for step in steps(bytecode) {
+            traversers = self.step(&step, traversers)?;
+        }
+        Ok(traversers)
+    }
+
+    fn run_one(&mut self, bytecode: &Bytecode, t: &Traverser) -> GremlinResult<Vec<Traverser>> {
+        self.run(bytecode, vec![t.clone()])
+    }
+
+    fn first(&mut self, bytecode: &Bytecode, t: &Traverser) -> GremlinResult<Option<GValue>> {
+        Ok(self
+            .run_one(bytecode, t)?
+            .into_iter()
+            .next()
+            .map(|t| t.value))
+    }
+
+    fn produces(&mut self, bytecode: &Bytecode, t: &Traverser) -> GremlinResult<bool> {
+        Ok(!self.run_one(bytecode, t)?.is_empty())
+    }
+
+    fn step(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let args = step.args;
+        match step.operator {
+            "V" => {
+                let ids: Vec<GID> = args.iter().map(to_gid).collect();
+                self.flat_map(input, |e, _| {
+                    let vertices = e.graph.vertices().iter();
+                    Ok(if ids.is_empty() {
+                        vertices.map(GValue::from).collect()
+                    } else {
+                        ids.iter()
+                            .filter_map(|id| e.graph.vertex(id))
+                            .map(GValue::from)
+                            .collect()
+                    })
+                })
+            }
+            "E" => {
+                let ids: Vec<GID> = args.iter().map(to_gid).collect();
+                self.flat_map(input, |e, _| {
+                    let edges = e.graph.edges().iter();
+                    Ok(if ids.is_empty() {
+                        edges.cloned().map(GValue::from).collect()
+                    } else {
+                        ids.iter()
+                            .filter_map(|id| e.graph.edge(id))
+                            .cloned()
+                            .map(GValue::from)
+                            .collect()
+                    })
+                })
+            }
+            "inject" => self.flat_map(input, |_, _| Ok(args.to_vec())),
+            "addV" => {
+                let label = match args.first() {
+                    None => String::from("vertex"),
+                    Some(GValue::String(label)) => label.clone(),
+                    Some(other) => return Err(unexpected("addV", other)),
+                };
+                self.map(input, |e, _| {
+                    Ok(Some(e.graph.add_vertex(None, &label)?.into()))
+                })
+            }
+            "addE" => {
+                let label = match args.first() {
+                    Some(GValue::String(label)) => label.clone(),
+                    Some(other) => return Err(unexpected("addE", other)),
+                    None => return Err(unexpected("addE", &GValue::Null)),
+                };
+                self.map(input, |e, t| {
+                    let out_v = e.endpoint(step, "from", t)?;
+                    let in_v = e.endpoint(step, "to", t)?;
+                    Ok(Some(e.graph.add_edge(None, &label, &out_v, &in_v)?.into()))
+                })
+            }
+            "out" => self.adjacent(input, Direction::Out, args, false),
+            "in" => self.adjacent(input, Direction::In, args, false),
+            "both" => self.adjacent(input, Direction::Both, args, false),
+            "outE" => self.adjacent(input, Direction::Out, args, true),
+            "inE" => self.adjacent(input, Direction::In, args, true),
+            "bothE" => self.adjacent(input, Direction::Both, args, true),
+            "toE" => match args.split_first() {
+                Some((GValue::Direction(d), labels)) => {
+                    self.adjacent(input, d.clone(), labels, true)
+                }
+                _ => Err(unexpected_args("toE", args)),
+            },
+            "outV" => self.flat_map(input, |e, t| e.ends(&t.value, Direction::Out)),
+            "inV" => self.flat_map(input, |e, t| e.ends(&t.value, Direction::In)),
+            "bothV" => self.flat_map(input, |e, t| e.ends(&t.value, Direction::Both)),
+            "toV" => match args.first() {
+                Some(GValue::Direction(d)) => {
+                    self.flat_map(input, |e, t| e.ends(&t.value, d.clone()))
+                }
+                _ => Err(unexpected_args("toV", args)),
+            },
+            "otherV" => self.map(input, |e, t| {
+                let edge = match &t.value {
+                    GValue::Edge(edge) => edge,
+                    other => return Err(unexpected("otherV", other)),
+                };
+                let previous = t.path.iter().rev().nth(1).map(|(_, v)| v);
+                let end = match previous {
+                    Some(GValue::Vertex(v)) if v.id() == edge.out_v().id() => edge.in_v(),
+                    _ => edge.out_v(),
+                };
+                Ok(Some(e.vertex(end).into()))
+            }),
+            "properties" => {
+                let keys = strings(args)?;
+                self.flat_map(input, |e, t| e.properties(&t.value, &keys))
+            }
+            "values" => {
+                let keys = strings(args)?;
+                self.flat_map(input, |e, t| {
+                    Ok(e.properties(&t.value, &keys)?
+                        .iter()
+                        .filter_map(|p| token(&T::Value, p))
+                        .collect())
+                })
+            }
+            "valueMap" | "propertyMap" | "elementMap" => {
+                let keys = strings(args)?;
+                let tokens = step.operator == "elementMap" || !step.modulators("with").is_empty();
+                self.map(input, |e, t| {
+                    e.element_map(step, &t.value, &keys, tokens).map(Some)
+                })
+            }
+            "id" => self.map(input, |_, t| Ok(token(&T::Id, &t.value))),
+            "label" => self.map(input, |_, t| Ok(token(&T::Label, &t.value))),
+            "key" => self.map(input, |_, t| Ok(token(&T::Key, &t.value))),
+            "value" => self.map(input, |_, t| Ok(token(&T::Value, &t.value))),
+            "constant" => match args.first() {
+                Some(value) => self.map(input, |_, _| Ok(Some(value.clone()))),
+                None => Err(unexpected_args("constant", args)),
+            },
+            "identity" | "barrier" => Ok(input),
+            "as" => {
+                let labels = strings(args)?;
+                Ok(input
+                    .into_iter()
+                    .map(|mut t| {
+                        if t.path.is_empty() {
+                            t.path.push((vec![], t.value.clone()));
+                        }
+                        if let Some((names, _)) = t.path.last_mut() {
+                            names.extend(labels.iter().cloned());
+                        }
+                        t
+                    })
+                    .collect())
+            }
+            "path" => self.map(input, |e, t| {
+                let mut labels = vec![];
+                let mut objects = vec![];
+                for (i, (names, object)) in t.path.iter().enumerate() {
+                    let names: Vec<GValue> = names.iter().cloned().map(GValue::from).collect();
+                    labels.push(GValue::Set(names.into()));
+                    let object = e.by(step.by(i), &Traverser::fresh(object.clone()))?;
+                    objects.push(object.unwrap_or(GValue::Null));
+                }
+                Ok(Some(
+                    Path::new(GValue::List(labels.into()), List::new(objects)).into(),
+                ))
+            }),
+            "select" => self.select(step, input),
+            "project" => {
+                let keys = strings(args)?;
+                self.map(input, |e, t| {
+                    let mut map = HashMap::new();
+                    for (i, key) in keys.iter().enumerate() {
+                        if let Some(value) = e.by(step.by(i), t)? {
+                            map.insert(GKey::String(key.clone()), value);
+                        }
+                    }
+                    Ok(Some(GValue::Map(map.into())))
+                })
+            }
+            "has" => {
+                let (label, key, predicate) = match args {
+                    [key] => (None, key, None),
+                    [key, predicate] => (None, key, Some(predicate)),
+                    [label, key, predicate] => (Some(label), key, Some(predicate)),
+                    _ => return Err(unexpected_args("has", args)),
+                };
+                self.filter(input, |e, t| {
+                    if let Some(label) = label {
+                        if !test(label, &token(&T::Label, &t.value).unwrap_or(GValue::Null))? {
+                            return Ok(false);
+                        }
+                    }
+                    let values: Vec<GValue> = match key {
+                        GValue::T(key) => token(key, &t.value).into_iter().collect(),
+                        GValue::String(key) => e
+                            .properties(&t.value, std::slice::from_ref(key))?
+                            .iter()
+                            .filter_map(|p| token(&T::Value, p))
+                            .collect(),
+                        other => return Err(unexpected("has", other)),
+                    };
+                    match predicate {
+                        None => Ok(!values.is_empty()),
+                        Some(predicate) => any(&values, |v| test(predicate, v)),
+                    }
+                })
+            }
+            "hasNot" => {
+                let keys = strings(args)?;
+                self.filter(input, |e, t| Ok(e.properties(&t.value, &keys)?.is_empty()))
+            }
+            "hasLabel" => self.has_token(input, T::Label, args),
+            "hasId" => self.has_token(input, T::Id, args),
+            "hasKey" => self.has_token(input, T::Key, args),
+            "hasValue" => self.has_token(input, T::Value, args),
+            "is" => match args.first() {
+                Some(predicate) => self.filter(input, |_, t| test(predicate, &t.value)),
+                None => Err(unexpected_args("is", args)),
+            },
+            "where" => match args {
+                [GValue::Bytecode(code)] => self.filter(input, |e, t| e.produces(code, t)),
+                [predicate] => self.filter(input, |e, t| {
+                    let value = e.by(step.by(0), t)?;
+                    match (value, e.resolve(step, predicate, t)?) {
+                        (Some(value), Some(predicate)) => test(&predicate, &value),
+                        _ => Ok(false),
+                    }
+                }),
+                [GValue::String(start), predicate] => self.filter(input, |e, t| {
+                    let start = match e.scope_value(t, start, &Pop::Last) {
+                        Some(start) => e.by(step.by(0), &t.split(start))?,
+                        None => None,
+                    };
+                    match (start, e.resolve(step, predicate, t)?) {
+                        (Some(start), Some(predicate)) => test(&predicate, &start),
+                        _ => Ok(false),
+                    }
+                }),
+                _ => Err(unexpected_args("where", args)),
+            },
+            "filter" => match args {
+                [GValue::Bytecode(code)] => self.filter(input, |e, t| e.produces(code, t)),
+                _ => Err(unexpected_args("filter", args)),
+            },
+            "not" => match args {
+                [GValue::Bytecode(code)] => self.filter(input, |e, t| Ok(!e.produces(code, t)?)),
+                _ => Err(unexpected_args("not", args)),
+            },
+            "and" | "or" => {
+                let codes = bytecodes(step.operator, args)?;
+                let and = step.operator == "and";
+                self.filter(input, |e, t| {
+                    for code in codes.iter() {
+                        if e.produces(code, t)? != and {
+                            return Ok(!and);
+                        }
+                    }
+                    Ok(and)
+                })
+            }
+            "simplePath" | "cyclicPath" => {
+                let simple = step.operator == "simplePath";
+                self.filter(input, |_, t| {
+                    let objects: Vec<&GValue> = t.path.iter().map(|(_, v)| v).collect();
+                    let cyclic = objects
+                        .iter()
+                        .enumerate()
+                        .any(|(i, a)| objects[i + 1..].iter().any(|b| equals(a, b)));
+                    Ok(cyclic != simple)
+                })
+            }
+            "dedup" => {
+                let labels = strings(args)?;
+                let mut seen: Vec<GValue> = vec![];
+                self.filter(input, |e, t| {
+                    let key = if labels.is_empty() {
+                        e.by(step.by(0), t)?.unwrap_or(GValue::Null)
+                    } else {
+                        let values = labels.iter().map(|l| e.scope_value(t, l, &Pop::Last));
+                        GValue::List(
+                            values
+                                .map(|v| v.unwrap_or(GValue::Null))
+                                .collect::<Vec<_>>()
+                                .into(),
+                        )
+                    };
+                    if seen.iter().any(|s| equals(s, &key)) {
+                        Ok(false)
+                    } else {
+                        seen.push(key);
+                        Ok(true)
+                    }
+                })
+            }
+            "limit" | "range" | "skip" | "tail" => self.range(step, input),
+            "count" => {
+                if local(args) {
+                    self.map(input, |_, t| Ok(Some(GValue::Int64(size(&t.value) as i64))))
+                } else {
+                    Ok(vec![Traverser::fresh(GValue::Int64(input.len() as i64))])
+                }
+            }
+            "sum" | "max" | "min" | "mean" => {
+                let operator = step.operator;
+                if local(args) {
+                    self.map(input, |_, t| reduce(operator, elements(&t.value)))
+                } else {
+                    let values = input.into_iter().map(|t| t.value).collect();
+                    Ok(reduce(operator, values)?
+                        .map(Traverser::fresh)
+                        .into_iter()
+                        .collect())
+                }
+            }
+            "fold" => {
+                let values: Vec<GValue> = input.into_iter().map(|t| t.value).collect();
+                let folded = match args {
+                    [] => GValue::List(values.into()),
+                    [seed, GValue::Operator(operator)] => {
+                        let mut folded = seed.clone();
+                        for value in values {
+                            folded = apply(operator, folded, value)?;
+                        }
+                        folded
+                    }
+                    _ => return Err(unexpected_args("fold", args)),
+                };
+                Ok(vec![Traverser::fresh(folded)])
+            }
+            "unfold" => self.flat_map(input, |_, t| {
+                Ok(match &t.value {
+                    GValue::List(_) | GValue::Set(_) => elements(&t.value),
+                    GValue::Map(map) => map
+                        .iter()
+                        .map(|(k, v)| {
+                            let mut entry = HashMap::new();
+                            entry.insert(k.clone(), v.clone());
+                            GValue::Map(entry.into())
+                        })
+                        .collect(),
+                    other => vec![other.clone()],
+                })
+            }),
+            "order" => self.order(step, input),
+            "group" | "groupCount" => self.group(step, input),
+            "aggregate" | "store" => {
+                let key = match args {
+                    [GValue::String(key)] => key.clone(),
+                    _ => return Err(unexpected_args(step.operator, args)),
+                };
+                let mut values = match self.side_effects.remove(&key) {
+                    Some(GValue::List(values)) => values.into_iter().collect(),
+                    _ => vec![],
+                };
+                for t in input.iter() {
+                    values.push(self.by(step.by(0), t)?.unwrap_or(GValue::Null));
+                }
+                self.side_effects.insert(key, GValue::List(values.into()));
+                Ok(input)
+            }
+            "cap" => {
+                let keys = strings(args)?;
+                let value = match keys.as_slice() {
+                    [key] => self.side_effects.get(key).cloned().unwrap_or(GValue::Null),
+                    _ => {
+                        let values = keys.iter().map(|k| {
+                            let value = self.side_effects.get(k).cloned();
+                            (GKey::String(k.clone()), value.unwrap_or(GValue::Null))
+                        });
+                        GValue::Map(values.collect::<HashMap<_, _>>().into())
+                    }
+                };
+                Ok(vec![Traverser::fresh(value)])
+            }
+            "sideEffect" => match args {
+                [GValue::Bytecode(code)] => {
+                    for t in input.iter() {
+                        self.run_one(code, t)?;
+                    }
+                    Ok(input)
+                }
+                _ => Err(unexpected_args("sideEffect", args)),
+            },
+            "map" => match args {
+                [GValue::Bytecode(code)] => self.map(input, |e, t| e.first(code, t)),
+                _ => Err(unexpected_args("map", args)),
+            },
+            "flatMap" | "local" => match args {
+                [GValue::Bytecode(code)] => {
+                    let mut out = vec![];
+                    for t in input.iter() {
+                        out.extend(self.run_one(code, t)?);
+                    }
+                    Ok(out)
+                }
+                _ => Err(unexpected_args(step.operator, args)),
+            },
+            "optional" => match args {
+                [GValue::Bytecode(code)] => {
+                    let mut out = vec![];
+                    for t in input {
+                        let results = self.run_one(code, &t)?;
+                        if results.is_empty() {
+                            out.push(t);
+                        } else {
+                            out.extend(results);
+                        }
+                    }
+                    Ok(out)
+                }
+                _ => Err(unexpected_args("optional", args)),
+            },
+            "union" | "coalesce" => {
+                let codes = bytecodes(step.operator, args)?;
+                let union = step.operator == "union";
+                let mut out = vec![];
+                for t in input.iter() {
+                    for code in codes.iter() {
+                        let results = self.run_one(code, t)?;
+                        let found = !results.is_empty();
+                        out.extend(results);
+                        if found && !union {
+                            break;
+                        }
+                    }
+                }
+                Ok(out)
+            }
+            "choose" => self.choose(step, input),
+            "repeat" => self.repeat(step, input),
+            "loops" => self.map(input, |_, t| {
+                Ok(Some(GValue::Int32(
+                    t.loops.last().copied().unwrap_or(0) as i32
+                )))
+            }),
+            "property" => self.property(step, input),
+            "drop" => {
+                for t in input.iter() {
+                    match &t.value {
+                        GValue::Vertex(v) => self.graph.remove_vertex(v.id()),
+                        GValue::Edge(e) => self.graph.remove_edge(e.id()),
+                        GValue::VertexProperty(p) => self.graph.remove_vertex_property(p),
+                        GValue::Property(p) => match t.path.iter().rev().nth(1) {
+                            Some((_, GValue::Edge(e))) => {
+                                self.graph.remove_edge_property(e.id(), p.label())?
+                            }
+                            _ => return Err(unexpected("drop", &t.value)),
+                        },
+                        other => return Err(unexpected("drop", other)),
+                    }
+                }
+                Ok(vec![])
+            }
+            operator => Err(unsupported(operator)),
+        }
+    }
+
+    fn map<F>(&mut self, input: Vec<Traverser>, mut f: F) -> GremlinResult<Vec<Traverser>>
+    where
+        F: FnMut(&mut Self, &Traverser) -> GremlinResult<Option<GValue>>,
+    {
+        let mut out = Vec::with_capacity(input.len());
+        for t in input.iter() {
+            if let Some(value) = f(self, t)? {
+                out.push(t.split(value));
+            }
+        }
+        Ok(out)
+    }
+
+    fn flat_map<F>(&mut self, input: Vec<Traverser>, mut f: F) -> GremlinResult<Vec<Traverser>>
+    where
+        F: FnMut(&mut Self, &Traverser) -> GremlinResult<Vec<GValue>>,
+    {
+        let mut out = vec![];
+        for t in input.iter() {
+            out.extend(f(self, t)?.into_iter().map(|value| t.split(value)));
+        }
+        Ok(out)
+    }
+
+    fn filter<F>(&mut self, input: Vec<Traverser>, mut f: F) -> GremlinResult<Vec<Traverser>>
+    where
+        F: FnMut(&mut Self, &Traverser) -> GremlinResult<bool>,
+    {
+        let mut out = vec![];
+        for t in input {
+            if f(self, &t)? {
+                out.push(t);
+            }
+        }
+        Ok(out)
+    }
+
+    // the state of an element in the graph, traversers keep the copy they were given
+    fn current(&self, value: GValue) -> GValue {
+        match value {
+            GValue::Vertex(v) => self.vertex(&v).into(),
+            GValue::Edge(e) => self.graph.edge(e.id()).cloned().unwrap_or(e).into(),
+            GValue::List(values) => GValue::List(
+                values
+                    .into_iter()
+                    .map(|v| self.current(v))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            other => other,
+        }
+    }
+
+    fn vertex(&self, vertex: &Vertex) -> Vertex {
+        self.graph
+            .vertex(vertex.id())
+            .cloned()
+            .unwrap_or_else(|| vertex.clone())
+    }
+
+    fn properties(&self, value: &GValue, keys: &[String]) -> GremlinResult<Vec<GValue>> {
+        match value {
+            GValue::Vertex(v) => {
+                let v = self.vertex(v);
+                Ok(sorted_keys(keys, v.iter().map(|(k, _)| k))
+                    .iter()
+                    .flat_map(|k| v.properties(k).iter().cloned().map(GValue::from))
+                    .collect())
+            }
+            GValue::Edge(e) => {
+                let e = self.graph.edge(e.id()).unwrap_or(e);
+                Ok(sorted_keys(keys, e.iter().map(|(k, _)| k))
+                    .iter()
+                    .filter_map(|k| e.property(k).cloned().map(GValue::from))
+                    .collect())
+            }
+            // meta-properties are not stored
+            GValue::VertexProperty(_) => Ok(vec![]),
+            other => Err(unexpected("properties", other)),
+        }
+    }
+
+    fn element_map(
+        &mut self,
+        step: &Step,
+        value: &GValue,
+        keys: &[String],
+        tokens: bool,
+    ) -> GremlinResult<GValue> {
+        let mut map = HashMap::new();
+        let single = step.operator == "elementMap" || matches!(value, GValue::Edge(_));
+        let mut grouped: Vec<(String, Vec<GValue>)> = vec![];
+        for property in self.properties(value, keys)? {
+            let key = match token(&T::Key, &property) {
+                Some(GValue::String(key)) => key,
+                _ => continue,
+            };
+            let property = match step.operator {
+                "propertyMap" => property,
+                _ => token(&T::Value, &property).unwrap_or(GValue::Null),
+            };
+            match grouped.iter_mut().find(|(k, _)| k == &key) {
+                Some((_, values)) => values.push(property),
+                None => grouped.push((key, vec![property])),
+            }
+        }
+        for (key, mut values) in grouped {
+            let value = if single {
+                values.remove(0)
+            } else {
+                GValue::List(values.into())
+            };
+            let value = self
+                .by(step.by(0), &Traverser::fresh(value))?
+                .unwrap_or(GValue::Null);
+            map.insert(GKey::String(key), value);
+        }
+        if tokens {
+            map.insert(GKey::T(T::Id), token(&T::Id, value).unwrap_or(GValue::Null));
+            map.insert(
+                GKey::T(T::Label),
+                token(&T::Label, value).unwrap_or(GValue::Null),
+            );
+            if let (GValue::Edge(e), "elementMap") = (value, step.operator) {
+                for (direction, v) in [(Direction::In, e.in_v()), (Direction::Out, e.out_v())] {
+                    let mut end = HashMap::new();
+                    end.insert(GKey::T(T::Id), v.id().to_gvalue());
+                    end.insert(GKey::T(T::Label), GValue::from(v.label().clone()));
+                    map.insert(GKey::Direction(direction), GValue::Map(end.into()));
+                }
+            }
+        }
+        Ok(GValue::Map(map.into()))
+    }
+
+    fn adjacent(
+        &mut self,
+        input: Vec<Traverser>,
+        direction: Direction,
+        labels: &[GValue],
+        edges: bool,
+    ) -> GremlinResult<Vec<Traverser>> {
+        let labels = strings(labels)?;
+        self.flat_map(input, |e, t| {
+            let id = match &t.value {
+                GValue::Vertex(v) => v.id(),
+                other => return Err(unexpected("adjacent", other)),
+            };
+            let mut out = vec![];
+            for (outgoing, incoming) in [
+                (Direction::Out, Direction::In),
+                (Direction::In, Direction::Out),
+            ] {
+                if direction != outgoing && direction != Direction::Both {
+                    continue;
+                }
+                for edge in e.graph.edges().iter() {
+                    if end(edge, &outgoing).id() != id
+                        || (!labels.is_empty() && !labels.contains(edge.label()))
+                    {
+                        continue;
+                    }
+                    out.push(if edges {
+                        edge.clone().into()
+                    } else {
+                        e.vertex(end(edge, &incoming)).into()
+                    });
+                }
+            }
+            Ok(out)
+        })
+    }
+
+    fn ends(&self, value: &GValue, direction: Direction) -> GremlinResult<Vec<GValue>> {
+        let edge = match value {
+            GValue::Edge(edge) => edge,
+            other => return Err(unexpected("edge ends", other)),
+        };
+        Ok(match direction {
+            Direction::Both => vec![
+                self.vertex(edge.out_v()).into(),
+                self.vertex(edge.in_v()).into(),
+            ],
+            direction => vec![self.vertex(end(edge, &direction)).into()],
+        })
+    }
+
+    // the vertex given by a from() or to() modulator of addE(), the current one otherwise
+    fn endpoint(&mut self, step: &Step, modulator: &str, t: &Traverser) -> GremlinResult<GID> {
+        let value = match step
+            .modulators(modulator)
+            .last()
+            .and_then(|m| m.args().first())
+        {
+            None => Some(t.value.clone()),
+            Some(GValue::String(label)) => self.scope_value(t, label, &Pop::Last),
+            Some(GValue::Bytecode(code)) => self.first(code, t)?,
+            Some(other) => Some(other.clone()),
+        };
+        match value {
+            Some(GValue::Vertex(v)) => Ok(v.id().clone()),
+            Some(GValue::Null) | None => Err(GremlinError::Generic(format!(
+                "The {}() vertex of addE() was not found",
+                modulator
+            ))),
+            Some(id) => Ok(to_gid(&id)),
+        }
+    }
+
+    fn has_token(
+        &mut self,
+        input: Vec<Traverser>,
+        key: T,
+        args: &[GValue],
+    ) -> GremlinResult<Vec<Traverser>> {
+        self.filter(input, |_, t| match token(&key, &t.value) {
+            Some(value) => any(args, |predicate| test(predicate, &value)),
+            None => Ok(false),
+        })
+    }
+
+    // a value from the current map, the side-effects or the labeled steps of the path
+    fn scope_value(&self, t: &Traverser, key: &str, pop: &Pop) -> Option<GValue> {
+        if let GValue::Map(map) = &t.value {
+            if let Some(value) = map.get(key) {
+                return Some(value.clone());
+            }
+        }
+        if let Some(value) = self.side_effects.get(key) {
+            return Some(value.clone());
+        }
+        let mut values: Vec<GValue> = t
+            .path
+            .iter()
+            .filter(|(labels, _)| labels.iter().any(|l| l == key))
+            .map(|(_, v)| v.clone())
+            .collect();
+        match (pop, values.len()) {
+            (_, 0) => None,
+            (Pop::First, _) => Some(values.remove(0)),
+            (Pop::Last, _) | (Pop::Mixed, 1) => values.pop(),
+            (Pop::All, _) | (Pop::Mixed, _) => Some(GValue::List(values.into())),
+        }
+    }
+
+    // the value a by() modulator turns the traverser into, None when it has no result
+    fn by(&mut self, by: Option<&Instruction>, t: &Traverser) -> GremlinResult<Option<GValue>> {
+        match by.and_then(|i| i.args().first()) {
+            None | Some(GValue::Order(_)) => Ok(Some(t.value.clone())),
+            Some(GValue::String(key)) => Ok(match &t.value {
+                GValue::Map(map) => map.get(key.as_str()).cloned(),
+                value => self
+                    .properties(value, std::slice::from_ref(key))?
+                    .first()
+                    .and_then(|p| token(&T::Value, p)),
+            }),
+            Some(GValue::T(key)) => Ok(token(key, &t.value)),
+            Some(GValue::Column(column)) => select_column(column, &t.value).map(Some),
+            Some(GValue::Bytecode(code)) => self.first(code, t),
+            Some(other) => Err(unexpected("by", other)),
+        }
+    }
+
+    // substitutes the labels given to the predicate of where() by their value
+    fn resolve(
+        &mut self,
+        step: &Step,
+        predicate: &GValue,
+        t: &Traverser,
+    ) -> GremlinResult<Option<GValue>> {
+        let p = match predicate {
+            GValue::P(p) => p,
+            other => return Err(unexpected("where", other)),
+        };
+        let value = match (p.operator().as_str(), p.value()) {
+            ("and", GValue::List(predicates)) | ("or", GValue::List(predicates)) => {
+                let mut resolved = vec![];
+                for predicate in predicates.iter() {
+                    match self.resolve(step, predicate, t)? {
+                        Some(predicate) => resolved.push(predicate),
+                        None => return Ok(None),
+                    }
+                }
+                GValue::List(resolved.into())
+            }
+            (_, GValue::String(label)) => match self.scope_value(t, label, &Pop::Last) {
+                Some(value) => match self.by(step.by(1), &t.split(value))? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                },
+                None => return Ok(None),
+            },
+            (_, other) => other.clone(),
+        };
+        Ok(Some(P::new(p.operator().clone(), value).into()))
+    }
+
+    fn select(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let args = step.args;
+        let (pop, keys) = match args {
+            [GValue::Column(column)] => {
+                return self.map(input, |_, t| select_column(column, &t.value).map(Some))
+            }
+            [GValue::Bytecode(code)] => {
+                return self.map(input, |e, t| match e.first(code, t)? {
+                    Some(GValue::String(key)) => match e.scope_value(t, &key, &Pop::Last) {
+                        Some(value) => e.by(step.by(0), &t.split(value)),
+                        None => Ok(None),
+                    },
+                    _ => Ok(None),
+                })
+            }
+            [GValue::Pop(pop), keys @ ..] => (pop.clone(), strings(keys)?),
+            keys => (Pop::Last, strings(keys)?),
+        };
+        self.map(input, |e, t| {
+            let mut selected = HashMap::new();
+            for (i, key) in keys.iter().enumerate() {
+                let value = match e.scope_value(t, key, &pop) {
+                    Some(value) => e.by(step.by(i), &t.split(value))?,
+                    None => None,
+                };
+                match value {
+                    Some(value) if keys.len() == 1 => return Ok(Some(value)),
+                    Some(value) => {
+                        selected.insert(GKey::String(key.clone()), value);
+                    }
+                    None => return Ok(None),
+                }
+            }
+            Ok(Some(GValue::Map(selected.into())))
+        })
+    }
+
+    fn range(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let args = match step.args.first() {
+            Some(GValue::Scope(_)) | Some(GValue::String(_)) => &step.args[1..],
+            _ => step.args,
+        };
+        let numbers: Vec<i64> = args.iter().filter_map(as_i64).collect();
+        let bounds = |size: usize| -> GremlinResult<(usize, usize)> {
+            let size = size as i64;
+            let (low, high) = match (step.operator, numbers.as_slice()) {
+                ("limit", [n]) => (0, *n),
+                ("range", [low, high]) => (*low, *high),
+                ("skip", [n]) => (*n, -1),
+                ("tail", []) => (size - 1, -1),
+                ("tail", [n]) => (size - n, -1),
+                _ => return Err(unexpected_args(step.operator, step.args)),
+            };
+            let high = if high < 0 { size } else { high.min(size) };
+            let low = low.max(0).min(high);
+            Ok((low as usize, high as usize))
+        };
+        if local(step.args) {
+            self.map(input, |_, t| {
+                let values = elements(&t.value);
+                let (low, high) = bounds(values.len())?;
+                let mut values = values[low..high].to_vec();
+                Ok(Some(if values.len() == 1 {
+                    values.remove(0)
+                } else {
+                    GValue::List(values.into())
+                }))
+            })
+        } else {
+            let (low, high) = bounds(input.len())?;
+            Ok(input.into_iter().skip(low).take(high - low).collect())
+        }
+    }
+
+    fn order(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let by = step.modulators("by");
+        let orders: Vec<Order> = if by.is_empty() {
+            vec![Order::Asc]
+        } else {
+            by.iter()
+                .map(|i| match i.args().last() {
+                    Some(GValue::Order(order)) => order.clone(),
+                    _ => Order::Asc,
+                })
+                .collect()
+        };
+        let compare_keys = |a: &[GValue], b: &[GValue]| {
+            for (order, (a, b)) in orders.iter().zip(a.iter().zip(b.iter())) {
+                let ordering = match order {
+                    Order::Asc => sort_order(a, b),
+                    Order::Desc => sort_order(b, a),
+                    Order::Shuffle => Ordering::Equal,
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        };
+        // values for which a by() has no result are left out
+        let keys = |e: &mut Self, t: &Traverser| -> GremlinResult<Option<Vec<GValue>>> {
+            let mut keys = vec![];
+            for i in 0..orders.len() {
+                match e.by(by.get(i).copied(), t)? {
+                    Some(key) => keys.push(key),
+                    None => return Ok(None),
+                }
+            }
+            Ok(Some(keys))
+        };
+        if local(step.args) {
+            return self.map(input, |e, t| {
+                let mut sorted = vec![];
+                for value in elements(&t.value) {
+                    if let Some(keys) = keys(e, &Traverser::fresh(value.clone()))? {
+                        sorted.push((keys, value));
+                    }
+                }
+                sorted.sort_by(|a, b| compare_keys(&a.0, &b.0));
+                Ok(Some(GValue::List(
+                    sorted
+                        .into_iter()
+                        .map(|(_, v)| v)
+                        .collect::<Vec<_>>()
+                        .into(),
+                )))
+            });
+        }
+        let mut sorted = vec![];
+        for t in input {
+            if let Some(keys) = keys(self, &t)? {
+                sorted.push((keys, t));
+            }
+        }
+        sorted.sort_by(|a, b| compare_keys(&a.0, &b.0));
+        Ok(sorted.into_iter().map(|(_, t)| t).collect())
+    }
+
+    fn group(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let mut groups: Vec<(GValue, Vec<Traverser>)> = vec![];
+        for t in input.iter() {
+            // traversers for which the key by() has no result are left out
+            let key = match self.by(step.by(0), t)? {
+                Some(key) => key,
+                None => continue,
+            };
+            match groups.iter_mut().find(|(k, _)| equals(k, &key)) {
+                Some((_, members)) => members.push(t.clone()),
+                None => groups.push((key, vec![t.clone()])),
+            }
+        }
+        let value_by = step.modulators("by").get(1).copied();
+        let mut map = HashMap::new();
+        for (key, members) in groups {
+            let value = if step.operator == "groupCount" {
+                GValue::Int64(members.len() as i64)
+            } else {
+                self.group_value(value_by, members)?
+            };
+            map.insert(GKey::from_gvalue(key)?, value);
+        }
+        let map = GValue::Map(map.into());
+        match step.args {
+            [] => Ok(vec![Traverser::fresh(map)]),
+            [GValue::String(key)] => {
+                self.side_effects.insert(key.clone(), map);
+                Ok(input)
+            }
+            args => Err(unexpected_args(step.operator, args)),
+        }
+    }
+
+    fn group_value(
+        &mut self,
+        by: Option<&Instruction>,
+        members: Vec<Traverser>,
+    ) -> GremlinResult<GValue> {
+        if let Some([GValue::Bytecode(code)]) = by.map(|i| i.args().as_slice()) {
+            let results = self.run(code, members)?;
+            let reduced = code
+                .steps()
+                .last()
+                .map(|i| REDUCING.contains(&i.operator().as_str()))
+                == Some(true);
+            return Ok(if reduced {
+                results
+                    .into_iter()
+                    .next()
+                    .map(|t| t.value)
+                    .unwrap_or(GValue::Null)
+            } else {
+                GValue::List(
+                    results
+                        .into_iter()
+                        .map(|t| t.value)
+                        .collect::<Vec<_>>()
+                        .into(),
+                )
+            });
+        }
+        let mut values = vec![];
+        for t in members.iter() {
+            if let Some(value) = self.by(by, t)? {
+                values.push(value);
+            }
+        }
+        Ok(GValue::List(values.into()))
+    }
+
+    fn choose(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let mut out = vec![];
+        match step.args {
+            [GValue::Bytecode(predicate), GValue::Bytecode(then), rest @ ..] => {
+                let otherwise = match rest {
+                    [] => None,
+                    [GValue::Bytecode(otherwise)] => Some(otherwise),
+                    _ => return Err(unexpected_args("choose", step.args)),
+                };
+                for t in input {
+                    if self.produces(predicate, &t)? {
+                        out.extend(self.run_one(then, &t)?);
+                    } else if let Some(otherwise) = otherwise {
+                        out.extend(self.run_one(otherwise, &t)?);
+                    } else {
+                        out.push(t);
+                    }
+                }
+            }
+            [GValue::Bytecode(selector)] => {
+                let mut options = vec![];
+                for option in step.modulators("option") {
+                    match option.args().as_slice() {
+                        [key, GValue::Bytecode(code)] => options.push((key, code)),
+                        args => return Err(unexpected_args("option", args)),
+                    }
+                }
+                for t in input {
+                    let key = self.first(selector, &t)?;
+                    let mut chosen: Vec<&Bytecode> = options
+                        .iter()
+                        .filter(|(k, _)| match (k, &key) {
+                            (GValue::Pick(_), _) | (_, None) => false,
+                            (k, Some(key)) => equals(k, key),
+                        })
+                        .map(|(_, code)| *code)
+                        .collect();
+                    if chosen.is_empty() {
+                        chosen.extend(
+                            options
+                                .iter()
+                                .filter(|(k, _)| k == &&GValue::Pick(Pick::None))
+                                .map(|(_, code)| *code),
+                        );
+                    }
+                    let any = options
+                        .iter()
+                        .filter(|(k, _)| k == &&GValue::Pick(Pick::Any))
+                        .map(|(_, code)| *code);
+                    chosen.extend(any);
+                    if chosen.is_empty() {
+                        out.push(t);
+                        continue;
+                    }
+                    for code in chosen {
+                        out.extend(self.run_one(code, &t)?);
+                    }
+                }
+            }
+            args => return Err(unexpected_args("choose", args)),
+        }
+        Ok(out)
+    }
+
+    fn repeat(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let body = match step.args.last() {
+            Some(GValue::Bytecode(body)) => body,
+            _ => return Err(unexpected_args("repeat", step.args)),
+        };
+        let before = (
+            find(&step.before, "until"),
+            find(&step.before, "times"),
+            find(&step.before, "emit"),
+        );
+        let after = (
+            find(&step.modulators, "until"),
+            find(&step.modulators, "times"),
+            find(&step.modulators, "emit"),
+        );
+
+        let mut out = vec![];
+        let mut frontier: Vec<Traverser> = input
+            .into_iter()
+            .map(|mut t| {
+                t.loops.push(0);
+                t
+            })
+            .collect();
+        while !frontier.is_empty() {
+            let mut next = vec![];
+            for t in frontier {
+                if self.check(before, &t, &mut out)? {
+                    next.push(t);
+                }
+            }
+            frontier = vec![];
+            for mut t in self.run(body, next)? {
+                if let Some(loops) = t.loops.last_mut() {
+                    *loops += 1;
+                }
+                if self.check(after, &t, &mut out)? {
+                    frontier.push(t);
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    // Applies the until(), times() and emit() modulators of a repeat() to a traverser, returns
+    // whether it goes through the loop again
+    fn check(
+        &mut self,
+        (until, times, emit): (
+            Option<&Instruction>,
+            Option<&Instruction>,
+            Option<&Instruction>,
+        ),
+        t: &Traverser,
+        out: &mut Vec<Traverser>,
+    ) -> GremlinResult<bool> {
+        let loops = t.loops.last().copied().unwrap_or(0);
+        let stop = match (
+            until.map(|i| i.args().as_slice()),
+            times.and_then(|i| i.args().first()),
+        ) {
+            (Some([GValue::Bytecode(code)]), _) => self.produces(code, t)?,
+            (Some(args), _) => return Err(unexpected_args("until", args)),
+            (None, Some(times)) => as_i64(times).map(|n| loops >= n) == Some(true),
+            (None, None) => false,
+        };
+        if stop {
+            out.push(t.clone().leave());
+            return Ok(false);
+        }
+        let emitted = match emit.map(|i| i.args().as_slice()) {
+            None => false,
+            Some([]) => true,
+            Some([GValue::Bytecode(code)]) => self.produces(code, t)?,
+            Some(args) => return Err(unexpected_args("emit", args)),
+        };
+        if emitted {
+            out.push(t.clone().leave());
+        }
+        Ok(true)
+    }
+
+    fn property(&mut self, step: &Step, input: Vec<Traverser>) -> GremlinResult<Vec<Traverser>> {
+        let (cardinality, args) = match step.args.split_first() {
+            Some((GValue::Cardinality(c), args)) => (c.clone(), args),
+            _ => (Cardinality::Single, step.args),
+        };
+        let (key, value) = match args {
+            [key, value] => (key, value),
+            _ => return Err(unexpected_args("property", step.args)),
+        };
+        let mut out = vec![];
+        for mut t in input {
+            let value = match value {
+                GValue::Bytecode(code) => match self.first(code, &t)? {
+                    Some(value) => value,
+                    None => continue,
+                },
+                value => value.clone(),
+            };
+            t.value = match (&t.value, key) {
+                (GValue::Vertex(v), GValue::T(T::Id)) => self
+                    .graph
+                    .replace_vertex(v.id(), to_gid(&value), v.label())?
+                    .into(),
+                (GValue::Vertex(v), GValue::T(T::Label)) => match &value {
+                    GValue::String(label) => self
+                        .graph
+                        .replace_vertex(v.id(), v.id().clone(), label)?
+                        .into(),
+                    other => return Err(unexpected("property", other)),
+                },
+                (GValue::Vertex(v), GValue::String(key)) => {
+                    self.graph
+                        .set_vertex_property(v.id(), cardinality.clone(), key, value)?;
+                    self.vertex(v).into()
+                }
+                (GValue::Edge(e), GValue::T(T::Id)) => self
+                    .graph
+                    .replace_edge(e.id(), to_gid(&value), e.label())?
+                    .into(),
+                (GValue::Edge(e), GValue::String(key)) => {
+                    self.graph.set_edge_property(e.id(), key, value)?;
+                    self.current(GValue::Edge(e.clone()))
+                }
+                (other, _) => return Err(unexpected("property", other)),
+            };
+            out.push(t);
+        }
+        Ok(out)
+    }
+}
+
+fn unsupported(operator: &str) -> GremlinError {
+    GremlinError::Generic(format!(
+        "{}() is not supported by the embedded graph",
+        operator
+    ))
+}
+
+fn unexpected(operator: &str, value: &GValue) -> GremlinError {
+    GremlinError::Generic(format!("Unexpected {} in {}()", value, operator))
+}
+
+fn unexpected_args(operator: &str, args: &[GValue]) -> GremlinError {
+    GremlinError::Generic(format!(
+        "Unexpected arguments {:?} for {}()",
+        args, operator
+    ))
+}
+
+fn find<'a>(modulators: &[&'a Instruction], operator: &str) -> Option<&'a Instruction> {
+    modulators
+        .iter()
+        .find(|i| i.operator() == operator)
+        .copied()
+}
+
+fn strings(args: &[GValue]) -> GremlinResult<Vec<String>> {
+    args.iter()
+        .map(|arg| match arg {
+            GValue::String(s) => Ok(s.clone()),
+            other => Err(GremlinError::Generic(format!(
+                "Expected a string, found {}",
+                other
+            ))),
+        })
+        .collect()
+}
+
+fn bytecodes<'a>(operator: &str, args: &'a [GValue]) -> GremlinResult<Vec<&'a Bytecode>> {
+    args.iter()
+        .map(|arg| match arg {
+            GValue::Bytecode(code) => Ok(code),
+            other => Err(unexpected(operator, other)),
+        })
+        .collect()
+}
+
+fn local(args: &[GValue]) -> bool {
+    match args.first() {
+        Some(GValue::Scope(Scope::Local)) => true,
+        Some(GValue::String(scope)) => scope == "Local",
+        _ => false,
+    }
+}
+
+fn sorted_keys<'a, I>(keys: &[String], all: I) -> Vec<String>
+where
+    I: Iterator<Item = &'a String>,
+{
+    if keys.is_empty() {
+        let mut all: Vec<String> = all.cloned().collect();
+        all.sort();
+        all
+    } else {
+        keys.to_vec()
+    }
+}
+
+fn end<'a>(edge: &'a Edge, direction: &Direction) -> &'a Vertex {
+    match direction {
+        Direction::In => edge.in_v(),
+        _ => edge.out_v(),
+    }
+}
+
+// Numeric ids are stored as i64, so that `V(1)` finds the vertex added with the id `1i64`
+pub(crate) fn to_gid(value: &GValue) -> GID {
+    match value {
+        GValue::Int32(n) => GID::Int64(i64::from(*n)),
+        GValue::Int64(n) => GID::Int64(*n),
+        GValue::String(s) => GID::String(s.clone()),
+        GValue::Uuid(u) => GID::Uuid(*u),
+        GValue::Vertex(v) => to_gid(&v.id().to_gvalue()),
+        GValue::Edge(e) => to_gid(&e.id().to_gvalue()),
+        other => GID::Custom(Box::new(other.clone())),
+    }
+}
+
+fn token(key: &T, value: &GValue) -> Option<GValue> {
+    match (key, value) {
+        (T::Id, GValue::Vertex(v)) => Some(v.id().to_gvalue()),
+        (T::Id, GValue::Edge(e)) => Some(e.id().to_gvalue()),
+        (T::Id, GValue::VertexProperty(p)) => Some(p.id().to_gvalue()),
+        (T::Label, GValue::Vertex(v)) => Some(v.label().clone().into()),
+        (T::Label, GValue::Edge(e)) => Some(e.label().clone().into()),
+        (T::Label, GValue::VertexProperty(p)) | (T::Key, GValue::VertexProperty(p)) => {
+            Some(p.label().clone().into())
+        }
+        (T::Key, GValue::Property(p)) => Some(p.label().clone().into()),
+        (T::Value, GValue::VertexProperty(p)) => Some(p.value().clone()),
+        (T::Value, GValue::Property(p)) => Some(p.value().clone()),
+        _ => None,
+    }
+}
+
+fn select_column(column: &Column, value: &GValue) -> GremlinResult<GValue> {
+    match value {
+        GValue::Map(map) => Ok(GValue::List(
+            map.iter()
+                .map(|(k, v)| match column {
+                    Column::Keys => k.clone().into(),
+                    Column::Values => v.clone(),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        )),
+        other => Err(unexpected("select", other)),
+    }
+}
+
+fn elements(value: &GValue) -> Vec<GValue> {
+    match value {
+        GValue::List(values) => values.iter().cloned().collect(),
+        GValue::Set(values) => values.iter().cloned().collect(),
+        GValue::Map(map) => map.iter().map(|(_, v)| v.clone()).collect(),
+        GValue::Null => vec![],
+        other => vec![other.clone()],
+    }
+}
+
+fn size(value: &GValue) -> usize {
+    match value {
+        GValue::Map(map) => map.len(),
+        other => elements(other).len(),
+    }
+}
+
+fn any<F>(values: &[GValue], mut f: F) -> GremlinResult<bool>
+where
+    F: FnMut(&GValue) -> GremlinResult<bool>,
+{
+    for value in values {
+        if f(value)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn as_i64(value: &GValue) -> Option<i64> {
+    match value {
+        GValue::Int32(n) => Some(i64::from(*n)),
+        GValue::Int64(n) => Some(*n),
+        _ => None,
+    }
+}
+
+fn as_f64(value: &GValue) -> Option<f64> {
+    match value {
+        GValue::Int32(n) => Some(f64::from(*n)),
+        GValue::Int64(n) => Some(*n as f64),
+        GValue::Float(n) => Some(f64::from(*n)),
+        GValue::Double(n) => Some(*n),
+        _ => None,
+    }
+}
+
+// Compares values of the same kind, numbers are compared whatever their width
+fn compare(a: &GValue, b: &GValue) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (as_i64(a), as_i64(b)) {
+        return Some(a.cmp(&b));
+    }
+    if let (Some(a), Some(b)) = (as_f64(a), as_f64(b)) {
+        return a.partial_cmp(&b);
+    }
+    match (a, b) {
+        (GValue::String(a), GValue::String(b)) => Some(a.cmp(b)),
+        (GValue::Bool(a), GValue::Bool(b)) => Some(a.cmp(b)),
+        (GValue::Date(a), GValue::Date(b)) => Some(a.cmp(b)),
+        (GValue::Uuid(a), GValue::Uuid(b)) => Some(a.cmp(b)),
+        (GValue::Null, GValue::Null) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn equals(a: &GValue, b: &GValue) -> bool {
+    match (a, b) {
+        (GValue::List(a), GValue::List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| equals(a, b))
+        }
+        _ => match compare(a, b) {
+            Some(ordering) => ordering == Ordering::Equal,
+            None => a == b,
+        },
+    }
+}
+
+// A total order for order(), values of different kinds are sorted by kind and elements by id
+fn sort_order(a: &GValue, b: &GValue) -> Ordering {
+    if let Some(ordering) = compare(a, b) {
+        return ordering;
+    }
+    let id = |v: &GValue| token(&T::Id, v);
+    match (id(a), id(b)) {
+        (Some(a), Some(b)) => sort_order(&a, &b),
+        _ => kind(a).cmp(&kind(b)),
+    }
+}
+
+fn kind(value: &GValue) -> u8 {
+    match value {
+        GValue::Null => 0,
+        GValue::Bool(_) => 1,
+        GValue::Int32(_) | GValue::Int64(_) | GValue::Float(_) | GValue::Double(_) => 2,
+        GValue::Date(_) => 3,
+        GValue::String(_) => 4,
+        GValue::Uuid(_) => 5,
+        _ => 6,
+    }
+}
+
+fn test(predicate: &GValue, value: &GValue) -> GremlinResult<bool> {
+    match predicate {
+        GValue::P(p) => test_p(p.operator(), p.value(), value),
+        GValue::TextP(p) => test_text(p.operator(), p.value(), value),
+        other => Ok(equals(other, value)),
+    }
+}
+
+fn test_p(operator: &str, operand: &GValue, value: &GValue) -> GremlinResult<bool> {
+    let ordering = compare(value, operand);
+    let bounds = || match elements(operand).as_slice() {
+        [low, high] => Ok((compare(value, low), compare(value, high))),
+        _ => Err(unexpected(operator, operand)),
+    };
+    Ok(match operator {
+        "eq" => equals(value, operand),
+        "neq" => !equals(value, operand),
+        "lt" => ordering == Some(Ordering::Less),
+        "lte" => ordering.map(|o| o != Ordering::Greater) == Some(true),
+        "gt" => ordering == Some(Ordering::Greater),
+        "gte" => ordering.map(|o| o != Ordering::Less) == Some(true),
+        "inside" => bounds()? == (Some(Ordering::Greater), Some(Ordering::Less)),
+        "outside" => {
+            let (low, high) = bounds()?;
+            low == Some(Ordering::Less) || high == Some(Ordering::Greater)
+        }
+        "between" => {
+            let (low, high) = bounds()?;
+            low.map(|o| o != Ordering::Less) == Some(true) && high == Some(Ordering::Less)
+        }
+        "within" => elements(operand).iter().any(|e| equals(value, e)),
+        "without" => !elements(operand).iter().any(|e| equals(value, e)),
+        "and" => {
+            for predicate in elements(operand) {
+                if !test(&predicate, value)? {
+                    return Ok(false);
+                }
+            }
+            true
+        }
+        "or" => any(&elements(operand), |predicate| test(predicate, value))?,
+        "not" => !test(operand, value)?,
+        other => return Err(unsupported(other)),
+    })
+}
+
+fn test_text(operator: &str, operand: &GValue, value: &GValue) -> GremlinResult<bool> {
+    let (value, operand) = match (value, operand) {
+        (GValue::String(value), GValue::String(operand)) => (value, operand.as_str()),
+        _ => return Ok(false),
+    };
+    Ok(match operator {
+        "containing" => value.contains(operand),
+        "notContaining" => !value.contains(operand),
+        "startingWith" => value.starts_with(operand),
+        "notStartingWith" => !value.starts_with(operand),
+        "endingWith" => value.ends_with(operand),
+        "notEndingWith" => !value.ends_with(operand),
+        other => return Err(unsupported(other)),
+    })
+}
+
+// Adds numbers, the result has the widest of the two types
+fn add(a: &GValue, b: &GValue) -> GremlinResult<GValue> {
+    match (a, b) {
+        (GValue::Int32(a), GValue::Int32(b)) => Ok(a
+            .checked_add(*b)
+            .map(GValue::Int32)
+            .unwrap_or_else(|| GValue::Int64(i64::from(*a) + i64::from(*b)))),
+        _ => match (as_i64(a), as_i64(b), as_f64(a), as_f64(b)) {
+            (Some(a), Some(b), _, _) => Ok(GValue::Int64(a + b)),
+            (_, _, Some(a), Some(b)) => Ok(GValue::Double(a + b)),
+            _ => Err(GremlinError::Generic(format!("Cannot add {} and {}", a, b))),
+        },
+    }
+}
+
+fn reduce(operator: &str, values: Vec<GValue>) -> GremlinResult<Option<GValue>> {
+    let mut values = values.into_iter();
+    let first = match values.next() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+    let mut count = 1u32;
+    let mut result = first;
+    for value in values {
+        count += 1;
+        result = match operator {
+            "sum" | "mean" => add(&result, &value)?,
+            "max" if sort_order(&value, &result) == Ordering::Greater => value,
+            "min" if sort_order(&value, &result) == Ordering::Less => value,
+            _ => result,
+        };
+    }
+    if operator == "mean" {
+        return match as_f64(&result) {
+            Some(sum) => Ok(Some(GValue::Double(sum / f64::from(count)))),
+            None => Err(unexpected("mean", &result)),
+        };
+    }
+    Ok(Some(result))
+}
+
+fn apply(operator: &Operator, a: GValue, b: GValue) -> GremlinResult<GValue> {
+    match operator {
+        Operator::Sum | Operator::SumLong => add(&a, &b),
+        Operator::Max => Ok(if sort_order(&b, &a) == Ordering::Greater {
+            b
+        } else {
+            a
+        }),
+        Operator::Min => Ok(if sort_order(&b, &a) == Ordering::Less {
+            b
+        } else {
+            a
+        }),
+        Operator::Assign => Ok(b),
+        Operator::AddAll => {
+            let mut all = elements(&a);
+            all.extend(elements(&b));
+            Ok(GValue::List(all.into()))
+        }
+        other => Err(unsupported(&format!("fold with {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embedded::{EmbeddedTerminator, TinkerGraph};
+    use crate::process::traversal::{traversal, GraphTraversalSource, Order, __};
+    use crate::structure::{Cardinality, GValue, Vertex, P, T};
+
+    fn modern() -> GraphTraversalSource<EmbeddedTerminator> {
+        let g = traversal().with_embedded(TinkerGraph::new());
+        let vertex = |label: &str, name: &str, key: &str, value: GValue| -> Vertex {
+            g.add_v(label)
+                .property("name", name)
+                .property(key, value)
+                .next()
+                .unwrap()
+                .unwrap()
+        };
+        let marko = vertex("person", "marko", "age", 29.into());
+        let vadas = vertex("person", "vadas", "age", 27.into());
+        let lop = vertex("software", "lop", "lang", "java".into());
+        let josh = vertex("person", "josh", "age", 32.into());
+        let ripple = vertex("software", "ripple", "lang", "java".into());
+        let peter = vertex("person", "peter", "age", 35.into());
+
+        let edge = |from: &Vertex, label: &str, to: &Vertex, weight: f64| {
+            g.v(from.id())
+                .add_e(label)
+                .to(to)
+                .property("weight", weight)
+                .to_list()
+                .unwrap();
+        };
+        edge(&marko, "knows", &vadas, 0.5);
+        edge(&marko, "knows", &josh, 1.0);
+        edge(&marko, "created", &lop, 0.4);
+        edge(&josh, "created", &ripple, 1.0);
+        edge(&josh, "created", &lop, 0.4);
+        edge(&peter, "created", &lop, 0.2);
+        g
+    }
+
+    fn names(values: Vec<GValue>) -> Vec<String> {
+        values
+            .into_iter()
+            .map(|v| v.take::<String>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn embedded_navigation() {
+        let g = modern();
+
+        assert_eq!(Some(6), g.v(()).count().next().unwrap());
+        assert_eq!(Some(6), g.e(()).count().next().unwrap());
+
+        let known = g
+            .v(())
+            .has(("name", "marko"))
+            .out("knows")
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["vadas", "josh"], names(known));
+
+        let creators = g
+            .v(())
+            .has(("name", "lop"))
+            .in_("created")
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["marko", "josh", "peter"], names(creators));
+
+        let weights = g
+            .v(())
+            .has(("name", "josh"))
+            .out_e("created")
+            .values("weight")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec![GValue::Double(1.0), GValue::Double(0.4)], weights);
+
+        let vertex = g.v(()).has(("name", "vadas")).next().unwrap().unwrap();
+        assert_eq!("person", vertex.label());
+        assert_eq!(&GValue::Int32(27), vertex.property("age").unwrap().value());
+        assert!(g.v(vertex.id()).has_next().unwrap());
+    }
+
+    #[test]
+    fn embedded_filters_and_ordering() {
+        let g = modern();
+
+        let older = g
+            .v(())
+            .has(("age", P::gt(29)))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["josh", "peter"], names(older));
+
+        let by_age = g
+            .v(())
+            .has_label("person")
+            .order(())
+            .by(("age", Order::Desc))
+            .values("name")
+            .limit(3)
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["peter", "josh", "marko"], names(by_age));
+
+        let co_creators = g
+            .v(())
+            .has_label("person")
+            .as_("a")
+            .out("created")
+            .in_("created")
+            .where_(P::neq("a"))
+            .values("name")
+            .dedup(())
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["josh", "peter", "marko"], names(co_creators));
+
+        let without_software = g
+            .v(())
+            .has_label("person")
+            .not(__.out("created"))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["vadas"], names(without_software));
+    }
+
+    #[test]
+    fn embedded_reducing_steps() {
+        let g = modern();
+
+        let by_label = g
+            .v(())
+            .group()
+            .by(T::Label)
+            .by(__.count())
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(&GValue::Int64(4)), by_label.get("person"));
+        assert_eq!(Some(&GValue::Int64(2)), by_label.get("software"));
+
+        let langs = g.v(()).group_count().by("lang").next().unwrap().unwrap();
+        assert_eq!(Some(&GValue::Int64(2)), langs.get("java"));
+
+        let projected = g
+            .v(())
+            .has(("name", "marko"))
+            .project(vec!["name", "friends"])
+            .by("name")
+            .by(__.out("knows").count())
+            .next()
+            .unwrap()
+            .unwrap()
+            .take::<crate::structure::Map>()
+            .unwrap();
+        assert_eq!(Some(&GValue::from("marko")), projected.get("name"));
+        assert_eq!(Some(&GValue::Int64(2)), projected.get("friends"));
+
+        let ages = g.v(()).values("age").fold().next().unwrap().unwrap();
+        assert_eq!(4, ages.len());
+        assert_eq!(
+            Some(GValue::Int32(123)),
+            g.v(()).values("age").sum(()).next().unwrap()
+        );
+    }
+
+    #[test]
+    fn embedded_repeat_and_paths() {
+        let g = modern();
+
+        let reached = g
+            .v(())
+            .has(("name", "marko"))
+            .repeat(__.out(()))
+            .until(__.out(()).count().is(0))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["vadas", "lop", "ripple", "lop"], names(reached));
+
+        let paths = g
+            .v(())
+            .has(("name", "marko"))
+            .repeat(__.out(()))
+            .emit()
+            .path()
+            .by("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(5, paths.len());
+        assert_eq!(
+            &[
+                GValue::from("marko"),
+                GValue::from("josh"),
+                GValue::from("ripple")
+            ],
+            &paths[3].objects().iter().cloned().collect::<Vec<_>>()[..]
+        );
+
+        let two_hops = g
+            .v(())
+            .has(("name", "marko"))
+            .repeat(__.out(()))
+            .until(__.loops(()).is(2))
+            .values("name")
+            .to_list()
+            .unwrap();
+        assert_eq!(vec!["ripple", "lop"], names(two_hops));
+    }
+
+    #[test]
+    fn embedded_mutations() {
+        let g = modern();
+
+        g.v(())
+            .has(("name", "vadas"))
+            .property("age", 28)
+            .to_list()
+            .unwrap();
+        g.v(())
+            .has(("name", "vadas"))
+            .property_with_cardinality(Cardinality::List, "nick", "v")
+            .property_with_cardinality(Cardinality::List, "nick", "vv")
+            .to_list()
+            .unwrap();
+        let vadas = g.v(()).has(("name", "vadas")).next().unwrap().unwrap();
+        assert_eq!(&GValue::Int32(28), vadas.property("age").unwrap().value());
+        assert_eq!(2, vadas.properties("nick").len());
+
+        let upsert = || {
+            g.v(())
+                .has(("name", "stephen"))
+                .fold()
+                .coalesce::<Vertex, _>([
+                    __.unfold(),
+                    __.add_v("person").property("name", "stephen"),
+                ])
+                .next()
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(upsert().id(), upsert().id());
+        assert_eq!(Some(7), g.v(()).count().next().unwrap());
+
+        g.v(()).has(("name", "josh")).drop().to_list().unwrap();
+        assert_eq!(Some(6), g.v(()).count().next().unwrap());
+        assert_eq!(Some(3), g.e(()).count().next().unwrap());
+
+        g.e(()).has_label("knows").drop().to_list().unwrap();
+        assert_eq!(
+            Some(0),
+            g.v(())
+                .has(("name", "marko"))
+                .out("knows")
+                .count()
+                .next()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn embedded_unsupported_steps() {
+        let g = modern();
+
+        assert!(g.v(()).sample(1).to_list().is_err());
+    }
+}
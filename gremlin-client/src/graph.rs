@@ -0,0 +1,298 @@
+//! Building [petgraph](https://docs.rs/petgraph) graphs out of traversal results.
+//!
+//! A [GraphCollector](struct.GraphCollector.html) takes vertices, edges, paths and `subgraph()`
+//! results and turns them into a `petgraph::Graph` where every vertex, identified by its
+//! [GID](../enum.GID.html), appears only once. Node weights are [Vertex](../struct.Vertex.html)es
+//! and edge weights are [Edge](../struct.Edge.html)s, so labels and properties are kept and
+//! local algorithms can run on them without going back to the server.
+//!
+//! ```rust,no_run
+//! use gremlin_client::graph::GraphCollector;
+//! use gremlin_client::process::traversal::traversal;
+//! use gremlin_client::GremlinClient;
+//! use petgraph::algo::kosaraju_scc;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let client = GremlinClient::connect("localhost")?;
+//!     let g = traversal().with_remote(client);
+//!
+//!     let mut collector = GraphCollector::new();
+//!     collector.extend(g.v(1).both_e(()).to_list()?);
+//!
+//!     let graph = collector.into_graph();
+//!     println!("{:?}", kosaraju_scc(&graph));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::structure::{Edge, GValue, Path, Vertex, GID};
+use crate::{GremlinError, GremlinResult};
+use petgraph::graph::{EdgeIndex, Graph, NodeIndex};
+use petgraph::stable_graph::StableGraph;
+use std::collections::HashMap;
+
+/// Collects graph elements into a `petgraph::Graph`, deduplicating vertices and edges by id.
+#[derive(Debug, Default)]
+pub struct GraphCollector {
+    graph: Graph<Vertex, Edge>,
+    nodes: HashMap<GID, NodeIndex>,
+    edges: HashMap<GID, EdgeIndex>,
+}
+
+impl GraphCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vertex, or returns the index of the one already collected with the same id.
+    ///
+    /// A vertex only known as the end of an edge is replaced when it shows up with its
+    /// properties.
+    pub fn add_vertex(&mut self, vertex: Vertex) -> NodeIndex {
+        match self.nodes.get(vertex.id()) {
+            Some(index) => {
+                let node = &mut self.graph[*index];
+                if node.iter().next().is_none() && vertex.iter().next().is_some() {
+                    *node = vertex;
+                }
+                *index
+            }
+            None => {
+                let id = vertex.id().clone();
+                let index = self.graph.add_node(vertex);
+                self.nodes.insert(id, index);
+                index
+            }
+        }
+    }
+
+    /// Adds an edge and its two ends, or returns the index of the one already collected with
+    /// the same id.
+    pub fn add_edge(&mut self, edge: Edge) -> EdgeIndex {
+        if let Some(index) = self.edges.get(edge.id()) {
+            return *index;
+        }
+        let out_v = self.add_vertex(edge.out_v().clone());
+        let in_v = self.add_vertex(edge.in_v().clone());
+        let id = edge.id().clone();
+        let index = self.graph.add_edge(out_v, in_v, edge);
+        self.edges.insert(id, index);
+        index
+    }
+
+    /// Adds the vertices and edges of a path.
+    ///
+    /// Consecutive vertices are not linked, so the edges have to be part of the path,
+    /// e.g. `outE().inV().path()`.
+    pub fn add_path(&mut self, path: Path) -> GremlinResult<()> {
+        for object in path.objects().iter() {
+            self.add(object.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Adds a traversal result: a vertex, an edge, a path, the graph returned by
+    /// `subgraph()` or a list of them.
+    pub fn add(&mut self, value: GValue) -> GremlinResult<()> {
+        match value {
+            GValue::Vertex(vertex) => {
+                self.add_vertex(vertex);
+            }
+            GValue::Edge(edge) => {
+                self.add_edge(edge);
+            }
+            GValue::Path(path) => self.add_path(path)?,
+            GValue::List(list) => {
+                for value in list {
+                    self.add(value)?;
+                }
+            }
+            GValue::Set(set) => {
+                for value in set {
+                    self.add(value)?;
+                }
+            }
+            GValue::Map(mut map) => {
+                let vertices = map.remove("vertices");
+                let edges = map.remove("edges");
+                if vertices.is_none() || edges.is_none() || !map.is_empty() {
+                    return Err(GremlinError::Cast(String::from(
+                        "Cannot collect a map that is not a subgraph",
+                    )));
+                }
+                self.add(vertices.unwrap())?;
+                self.add(edges.unwrap())?;
+            }
+            GValue::Null => {}
+            other => {
+                return Err(GremlinError::Cast(format!(
+                    "Cannot collect {:?} into a graph",
+                    other
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    pub fn node_index(&self, id: &GID) -> Option<NodeIndex> {
+        self.nodes.get(id).copied()
+    }
+
+    pub fn edge_index(&self, id: &GID) -> Option<EdgeIndex> {
+        self.edges.get(id).copied()
+    }
+
+    pub fn graph(&self) -> &Graph<Vertex, Edge> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<Vertex, Edge> {
+        self.graph
+    }
+
+    /// Nodes and edges keep the indices returned by the collector.
+    pub fn into_stable_graph(self) -> StableGraph<Vertex, Edge> {
+        StableGraph::from(self.graph)
+    }
+}
+
+impl Extend<Vertex> for GraphCollector {
+    fn extend<I: IntoIterator<Item = Vertex>>(&mut self, iter: I) {
+        for vertex in iter {
+            self.add_vertex(vertex);
+        }
+    }
+}
+
+impl Extend<Edge> for GraphCollector {
+    fn extend<I: IntoIterator<Item = Edge>>(&mut self, iter: I) {
+        for edge in iter {
+            self.add_edge(edge);
+        }
+    }
+}
+
+impl std::iter::FromIterator<Vertex> for GraphCollector {
+    fn from_iter<I: IntoIterator<Item = Vertex>>(iter: I) -> Self {
+        let mut collector = GraphCollector::new();
+        collector.extend(iter);
+        collector
+    }
+}
+
+impl std::iter::FromIterator<Edge> for GraphCollector {
+    fn from_iter<I: IntoIterator<Item = Edge>>(iter: I) -> Self {
+        let mut collector = GraphCollector::new();
+        collector.extend(iter);
+        collector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphCollector;
+    use crate::structure::{GValue, Map, Path, Vertex, VertexProperty, GID};
+    use crate::{edge, vertex};
+    use petgraph::algo::{dijkstra, kosaraju_scc};
+    use std::collections::HashMap;
+
+    fn edge(id: i32, out_v: i32, in_v: i32) -> GValue {
+        edge!({
+            id => id,
+            label => "knows",
+            inV => { id => in_v, label => "person" },
+            outV => { id => out_v, label => "person" },
+            properties => {}
+        })
+        .into()
+    }
+
+    #[test]
+    fn collector_deduplicates_vertices_and_edges() {
+        let mut collector = GraphCollector::new();
+
+        collector
+            .add(vec![edge(7, 1, 2), edge(8, 2, 3), edge(7, 1, 2)].into())
+            .unwrap();
+
+        let graph = collector.into_graph();
+        assert_eq!(3, graph.node_count());
+        assert_eq!(2, graph.edge_count());
+    }
+
+    #[test]
+    fn collector_keeps_vertex_properties() {
+        let mut collector = GraphCollector::new();
+
+        collector.add(edge(7, 1, 2)).unwrap();
+        collector
+            .add(
+                Vertex::builder(1, "person")
+                    .property(VertexProperty::new(
+                        GID::Int64(0),
+                        "name",
+                        GValue::from("marko"),
+                    ))
+                    .build()
+                    .into(),
+            )
+            .unwrap();
+
+        let index = collector.node_index(&GID::Int32(1)).unwrap();
+        let graph = collector.into_stable_graph();
+        assert_eq!(2, graph.node_count());
+        assert_eq!("person", graph[index].label());
+        assert_eq!(
+            Some(&String::from("marko")),
+            graph[index].property("name").unwrap().get::<String>().ok()
+        );
+    }
+
+    #[test]
+    fn collector_reads_paths_and_subgraphs() {
+        let mut collector = GraphCollector::new();
+
+        let path = Path::builder()
+            .step(
+                Vec::<String>::new(),
+                vertex!({ id => 1, label => "person", properties => {}}),
+            )
+            .step(Vec::<String>::new(), edge(7, 1, 2))
+            .step(
+                Vec::<String>::new(),
+                vertex!({ id => 2, label => "person", properties => {}}),
+            )
+            .build();
+        collector.add(path.into()).unwrap();
+
+        let mut subgraph = HashMap::new();
+        subgraph.insert(
+            String::from("vertices"),
+            GValue::List(
+                vec![vertex!({ id => 3, label => "person", properties => {}}).into()].into(),
+            ),
+        );
+        subgraph.insert(
+            String::from("edges"),
+            GValue::List(vec![edge(8, 2, 3), edge(9, 3, 1)].into()),
+        );
+        collector.add(Map::from(subgraph).into()).unwrap();
+
+        let start = collector.node_index(&GID::Int32(1)).unwrap();
+        let end = collector.node_index(&GID::Int32(3)).unwrap();
+        let graph = collector.into_graph();
+
+        assert_eq!(
+            Some(&2),
+            dijkstra(&graph, start, Some(end), |_| 1).get(&end)
+        );
+        assert_eq!(1, kosaraju_scc(&graph).len());
+    }
+
+    #[test]
+    fn collector_rejects_other_values() {
+        let mut collector = GraphCollector::new();
+
+        assert!(collector.add(GValue::from("marko")).is_err());
+    }
+}
@@ -5,13 +5,23 @@ mod serializer_v2;
 mod serializer_v3;
 
 use crate::conversion::ToGValue;
-use crate::process::traversal::{Order, Scope};
-use crate::structure::{Cardinality, GValue, T};
+use crate::process::traversal::{Barrier, Instruction, Merge, Operator, Order, Pick, Scope, DT};
+use crate::structure::{Cardinality, Column, Custom, GKey, GValue, T};
 use serde_json::{json, Map, Value};
 use std::string::ToString;
 
 use crate::{GremlinError, GremlinResult};
 
+/// A typed value the deserializers do not support, kept as is, e.g. a provider specific id
+fn read_custom(val: &Value) -> Option<Custom> {
+    match (val.get("@type"), val.get("@value")) {
+        (Some(Value::String(type_name)), Some(value)) => {
+            Some(Custom::new(type_name.clone(), value.clone()))
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum GraphSON {
     V1,
@@ -90,27 +100,68 @@ impl GraphSON {
                 panic!("Bytecode not supported in GraphsonV1")
             }
             (_, GValue::Bytecode(code)) => {
-                let steps: GremlinResult<Vec<Value>> = code
-                    .steps()
-                    .iter()
-                    .map(|m| {
-                        let mut instruction = vec![];
-                        instruction.push(Value::String(m.operator().clone()));
-
-                        let arguments: GremlinResult<Vec<Value>> =
-                            m.args().iter().map(|a| self.write(a)).collect();
-
-                        instruction.extend(arguments?);
-                        Ok(Value::Array(instruction))
-                    })
-                    .collect();
+                let write_instructions = |instructions: &Vec<Instruction>| {
+                    instructions
+                        .iter()
+                        .map(|m| {
+                            let mut instruction = vec![];
+                            instruction.push(Value::String(m.operator().clone()));
+
+                            let arguments: GremlinResult<Vec<Value>> =
+                                m.args().iter().map(|a| self.write(a)).collect();
+
+                            instruction.extend(arguments?);
+                            Ok(Value::Array(instruction))
+                        })
+                        .collect::<GremlinResult<Vec<Value>>>()
+                };
+
+                let mut value = Map::new();
+                if !code.sources().is_empty() {
+                    value.insert(
+                        String::from("source"),
+                        json!(write_instructions(code.sources())?),
+                    );
+                }
+                value.insert(
+                    String::from("step"),
+                    json!(write_instructions(code.steps())?),
+                );
+
                 Ok(json!({
                     "@type" : "g:Bytecode",
-                    "@value" : {
-                        "step" : steps?
-                    }
+                    "@value" : value
                 }))
             }
+            (GraphSON::V1, GValue::Property(p)) => Ok(json!({
+                "key" : p.label(),
+                "value" : self.write(p.value())?,
+            })),
+            (_, GValue::Property(p)) => Ok(json!({
+                "@type" : "g:Property",
+                "@value" : {
+                    "key" : p.label(),
+                    "value" : self.write(p.value())?,
+                }
+            })),
+            (GraphSON::V1, GValue::VertexProperty(p)) => Ok(json!({
+                "id" : self.write(&p.id().to_gvalue())?,
+                "label" : p.label(),
+                "value" : self.write(p.value())?,
+            })),
+            (_, GValue::VertexProperty(p)) => Ok(json!({
+                "@type" : "g:VertexProperty",
+                "@value" : {
+                    "id" : self.write(&p.id().to_gvalue())?,
+                    "label" : p.label(),
+                    "value" : self.write(p.value())?,
+                }
+            })),
+            (GraphSON::V1, GValue::Custom(c)) => Ok(c.value().clone()),
+            (_, GValue::Custom(c)) => Ok(json!({
+                "@type" : c.type_name(),
+                "@value" : c.value(),
+            })),
             (_, GValue::Vertex(v)) => {
                 let id = self.write(&v.id().to_gvalue())?;
                 Ok(json!({
@@ -124,15 +175,7 @@ impl GraphSON {
                 let mut params = Map::new();
 
                 for (k, v) in map.iter() {
-                    params.insert(
-                        self.write(&k.clone().into())?
-                            .as_str()
-                            .ok_or_else(|| {
-                                GremlinError::Generic("Non-string key value.".to_string())
-                            })?
-                            .to_string(),
-                        self.write(&v)?,
-                    );
+                    params.insert(self.write_key(k)?, self.write(&v)?);
                 }
 
                 Ok(json!(params))
@@ -141,15 +184,7 @@ impl GraphSON {
                 let mut params = Map::new();
 
                 for (k, v) in map.iter() {
-                    params.insert(
-                        self.write(&k.clone().into())?
-                            .as_str()
-                            .ok_or_else(|| {
-                                GremlinError::Generic("Non-string key value.".to_string())
-                            })?
-                            .to_string(),
-                        self.write(&v)?,
-                    );
+                    params.insert(self.write_key(k)?, self.write(&v)?);
                 }
 
                 Ok(json!(params))
@@ -234,7 +269,117 @@ impl GraphSON {
                 }))
             }
 
+            (_, GValue::Direction(direction)) => Ok(json!({
+                "@type" : "g:Direction",
+                "@value" : direction.to_string(),
+            })),
+            (_, GValue::Merge(merge)) => {
+                let v = match merge {
+                    Merge::OnCreate => "onCreate",
+                    Merge::OnMatch => "onMatch",
+                    Merge::OutV => "outV",
+                    Merge::InV => "inV",
+                };
+                Ok(json!({
+                    "@type" : "g:Merge",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::DT(dt)) => {
+                let v = match dt {
+                    DT::Second => "second",
+                    DT::Minute => "minute",
+                    DT::Hour => "hour",
+                    DT::Day => "day",
+                };
+                Ok(json!({
+                    "@type" : "g:DT",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::Column(column)) => {
+                let v = match column {
+                    Column::Keys => "keys",
+                    Column::Values => "values",
+                };
+                Ok(json!({
+                    "@type" : "g:Column",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::Operator(operator)) => {
+                let v = match operator {
+                    Operator::Sum => "sum",
+                    Operator::Minus => "minus",
+                    Operator::Mult => "mult",
+                    Operator::Div => "div",
+                    Operator::Min => "min",
+                    Operator::Max => "max",
+                    Operator::Assign => "assign",
+                    Operator::And => "and",
+                    Operator::Or => "or",
+                    Operator::AddAll => "addAll",
+                    Operator::SumLong => "sumLong",
+                };
+                Ok(json!({
+                    "@type" : "g:Operator",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::Barrier(barrier)) => {
+                let v = match barrier {
+                    Barrier::NormSack => "normSack",
+                };
+                Ok(json!({
+                    "@type" : "g:Barrier",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::Pick(pick)) => {
+                let v = match pick {
+                    Pick::Any => "any",
+                    Pick::None => "none",
+                };
+                Ok(json!({
+                    "@type" : "g:Pick",
+                    "@value" : v
+                }))
+            }
+            (_, GValue::Binding(binding)) => Ok(json!({
+                "@type" : "g:Binding",
+                "@value" : {
+                    "key" : binding.key(),
+                    "value" : self.write(binding.value())?
+                }
+            })),
+
+            (_, GValue::Lambda(lambda)) => Ok(json!({
+                "@type" : "g:Lambda",
+                "@value" : {
+                    "script" : lambda.script(),
+                    "language" : lambda.language(),
+                    "arguments" : lambda.arguments()
+                }
+            })),
+
+            (_, GValue::Null) => Ok(Value::Null),
+
             (_, _) => panic!("Type {:?} not supported.", value),
         }
     }
+
+    // Maps before V3 are JSON objects, tokens would be read back as plain strings
+    fn write_key(&self, key: &GKey) -> GremlinResult<String> {
+        match key {
+            GKey::T(_) | GKey::Direction(_) => Err(GremlinError::Generic(format!(
+                "Map key {:?} requires GraphSON V3, e.g. in mergeV or mergeE",
+                key
+            ))),
+            _ => self
+                .write(&key.clone().into())?
+                .as_str()
+                .map(String::from)
+                .ok_or_else(|| GremlinError::Generic("Non-string key value.".to_string())),
+        }
+    }
 }
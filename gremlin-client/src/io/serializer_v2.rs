@@ -1,9 +1,11 @@
 //! GraphSON V2 [docs](http://tinkerpop.apache.org/docs/current/dev/io/)
 //!
 
+use crate::process::traversal::{Barrier, Bytecode, Merge, Operator, Order, Pick, Scope, DT};
 use crate::structure::{
-    Edge, GKey, GValue, IntermediateRepr, List, Map, Metric, Path, Property, Token,
-    TraversalExplanation, TraversalMetrics, Traverser, Vertex, VertexProperty, GID,
+    Binding, Branch, Cardinality, Column, Direction, Edge, GKey, GValue, IntermediateRepr, Lambda,
+    List, Map, Metric, Path, Pop, Property, TextP, Token, TraversalExplanation, TraversalMetrics,
+    Traverser, Tree, Vertex, VertexProperty, GID, P, T,
 };
 use crate::GremlinError;
 use crate::GremlinResult;
@@ -26,10 +28,14 @@ where
             GValue::String(d) => Ok(GID::String(d)),
             GValue::Int32(d) => Ok(GID::Int32(d)),
             GValue::Int64(d) => Ok(GID::Int64(d)),
-            _ => Err(GremlinError::Json(format!("{} cannot be an id", val))),
+            GValue::Uuid(d) => Ok(GID::Uuid(d)),
+            other => Ok(GID::Custom(Box::new(other))),
         },
         Err(e) => match e {
-            GremlinError::Json(_e) => Ok(GID::String(val.to_string())),
+            GremlinError::Json(_e) => Ok(match super::read_custom(val) {
+                Some(custom) => GID::Custom(Box::new(GValue::Custom(custom))),
+                None => GID::String(val.to_string()),
+            }),
             _ => Err(e),
         },
     }
@@ -187,6 +193,46 @@ where
     Ok(Path::new(labels, objects).into())
 }
 
+// Tree deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_tree)
+pub fn deserialize_tree<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::Array)?;
+    let mut branches = Vec::with_capacity(val.len());
+    for item in val {
+        let key = reader(&item["key"])?;
+        let tree = reader(&item["value"])?.take::<Tree>()?;
+        branches.push(Branch::new(key, tree));
+    }
+    Ok(Tree::new(branches).into())
+}
+
+// TinkerGraph deserializer, the result of a `subgraph()` step
+pub fn deserialize_tinker_graph<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let mut graph = HashMap::new();
+    for key in &["vertices", "edges"] {
+        let elements = match &val[*key] {
+            Value::Array(items) => items
+                .iter()
+                .map(reader)
+                .collect::<GremlinResult<Vec<_>>>()?,
+            Value::Null => vec![],
+            _ => {
+                return Err(GremlinError::Json(format!(
+                    "Value {:?} is not a valid tinker:graph",
+                    val
+                )))
+            }
+        };
+        graph.insert(String::from(*key), GValue::List(elements.into()));
+    }
+    Ok(Map::from(graph).into())
+}
+
 // Traversal Metrics deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_traversalmetrics)
 pub fn deserialize_metrics<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -247,6 +293,7 @@ where
         perc_duration,
         nested?,
     )
+    .with_annotations(annotations)
     .into())
 }
 
@@ -341,6 +388,223 @@ where
     Ok(Traverser::new(bulk, v).into())
 }
 
+// Bytecode deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_bytecode_2)
+pub fn deserialize_bytecode<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let mut code = Bytecode::new();
+
+    for (operator, args) in deserialize_instructions(reader, &val["source"])? {
+        code.add_source(operator, args);
+    }
+    for (operator, args) in deserialize_instructions(reader, &val["step"])? {
+        code.add_step(operator, args);
+    }
+    Ok(code.into())
+}
+
+// P deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_p_2)
+pub fn deserialize_p<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = get_value!(&val["predicate"], Value::String)?;
+    let value = reader(&val["value"])?;
+    Ok(P::new(operator.clone(), value).into())
+}
+
+// TextP deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_textp_2)
+pub fn deserialize_text_p<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = get_value!(&val["predicate"], Value::String)?;
+    let value = reader(&val["value"])?;
+    Ok(TextP::new(operator.clone(), value).into())
+}
+
+// Lambda deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_lambda_2)
+pub fn deserialize_lambda<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let script = get_value!(&val["script"], Value::String)?;
+    let language = val
+        .get("language")
+        .map(|f| get_value!(f, Value::String).map(Clone::clone))
+        .unwrap_or_else(|| Ok(String::from("gremlin-groovy")))?;
+    let arguments = val.get("arguments").and_then(Value::as_i64).unwrap_or(-1);
+
+    Ok(Lambda::with_language(script.clone(), language)
+        .with_arguments(arguments as i32)
+        .into())
+}
+
+// Binding deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_binding_2)
+pub fn deserialize_binding<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let key = get_value!(&val["key"], Value::String)?;
+    let value = reader(&val["value"])?;
+
+    Ok(Binding::new(key.clone(), value).into())
+}
+
+// Order deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_order_2)
+pub fn deserialize_order<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let order = match get_value!(val, Value::String)?.as_str() {
+        "asc" | "incr" => Order::Asc,
+        "desc" | "decr" => Order::Desc,
+        "shuffle" => Order::Shuffle,
+        other => return Err(GremlinError::Json(format!("Unknown Order {}", other))),
+    };
+    Ok(order.into())
+}
+
+// Scope deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_scope_2)
+pub fn deserialize_scope<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let scope = match get_value!(val, Value::String)?.as_str() {
+        "global" => Scope::Global,
+        "local" => Scope::Local,
+        other => return Err(GremlinError::Json(format!("Unknown Scope {}", other))),
+    };
+    Ok(scope.into())
+}
+
+// Pop deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_pop_2)
+pub fn deserialize_pop<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let pop = match get_value!(val, Value::String)?.as_str() {
+        "all" => Pop::All,
+        "first" => Pop::First,
+        "last" => Pop::Last,
+        "mixed" => Pop::Mixed,
+        other => return Err(GremlinError::Json(format!("Unknown Pop {}", other))),
+    };
+    Ok(pop.into())
+}
+
+// Cardinality deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_cardinality_2)
+pub fn deserialize_cardinality<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let cardinality = match get_value!(val, Value::String)?.as_str() {
+        "list" => Cardinality::List,
+        "set" => Cardinality::Set,
+        "single" => Cardinality::Single,
+        other => return Err(GremlinError::Json(format!("Unknown Cardinality {}", other))),
+    };
+    Ok(cardinality.into())
+}
+
+pub fn deserialize_direction<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let direction = match get_value!(val, Value::String)?.as_str() {
+        "OUT" => Direction::Out,
+        "IN" => Direction::In,
+        "BOTH" => Direction::Both,
+        other => return Err(GremlinError::Json(format!("Unknown Direction {}", other))),
+    };
+    Ok(direction.into())
+}
+
+pub fn deserialize_merge<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let merge = match get_value!(val, Value::String)?.as_str() {
+        "onCreate" => Merge::OnCreate,
+        "onMatch" => Merge::OnMatch,
+        "outV" => Merge::OutV,
+        "inV" => Merge::InV,
+        other => return Err(GremlinError::Json(format!("Unknown Merge {}", other))),
+    };
+    Ok(merge.into())
+}
+
+pub fn deserialize_dt<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let dt = match get_value!(val, Value::String)?.as_str() {
+        "second" => DT::Second,
+        "minute" => DT::Minute,
+        "hour" => DT::Hour,
+        "day" => DT::Day,
+        other => return Err(GremlinError::Json(format!("Unknown DT {}", other))),
+    };
+    Ok(dt.into())
+}
+
+pub fn deserialize_column<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let column = match get_value!(val, Value::String)?.as_str() {
+        "keys" => Column::Keys,
+        "values" => Column::Values,
+        other => return Err(GremlinError::Json(format!("Unknown Column {}", other))),
+    };
+    Ok(column.into())
+}
+
+pub fn deserialize_operator<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = match get_value!(val, Value::String)?.as_str() {
+        "sum" => Operator::Sum,
+        "minus" => Operator::Minus,
+        "mult" => Operator::Mult,
+        "div" => Operator::Div,
+        "min" => Operator::Min,
+        "max" => Operator::Max,
+        "assign" => Operator::Assign,
+        "and" => Operator::And,
+        "or" => Operator::Or,
+        "addAll" => Operator::AddAll,
+        "sumLong" => Operator::SumLong,
+        other => return Err(GremlinError::Json(format!("Unknown Operator {}", other))),
+    };
+    Ok(operator.into())
+}
+
+pub fn deserialize_barrier<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let barrier = match get_value!(val, Value::String)?.as_str() {
+        "normSack" => Barrier::NormSack,
+        other => return Err(GremlinError::Json(format!("Unknown Barrier {}", other))),
+    };
+    Ok(barrier.into())
+}
+
+pub fn deserialize_pick<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let pick = match get_value!(val, Value::String)?.as_str() {
+        "any" => Pick::Any,
+        "none" => Pick::None,
+        other => return Err(GremlinError::Json(format!("Unknown Pick {}", other))),
+    };
+    Ok(pick.into())
+}
+
 // deserialzer v2
 g_serializer_2!(deserializer_v2, {
     "g:Int32" => deserialize_g32,
@@ -357,10 +621,28 @@ g_serializer_2!(deserializer_v2, {
     "g:Property" => deserialize_property,
     "g:Edge" => deserialize_edge,
     "g:Path" => deserialize_path,
+    "g:Tree" => deserialize_tree,
+    "tinker:graph" => deserialize_tinker_graph,
     "g:TraversalMetrics" => deserialize_metrics,
     "g:Metrics" => deserialize_metric,
     "g:TraversalExplanation" => deserialize_explain,
-    "g:Traverser" => deserialize_traverser
+    "g:Traverser" => deserialize_traverser,
+    "g:Bytecode" => deserialize_bytecode,
+    "g:P" => deserialize_p,
+    "g:TextP" => deserialize_text_p,
+    "g:Order" => deserialize_order,
+    "g:Scope" => deserialize_scope,
+    "g:Pop" => deserialize_pop,
+    "g:Cardinality" => deserialize_cardinality,
+    "g:Direction" => deserialize_direction,
+    "g:Merge" => deserialize_merge,
+    "g:DT" => deserialize_dt,
+    "g:Column" => deserialize_column,
+    "g:Operator" => deserialize_operator,
+    "g:Barrier" => deserialize_barrier,
+    "g:Pick" => deserialize_pick,
+    "g:Lambda" => deserialize_lambda,
+    "g:Binding" => deserialize_binding
 });
 
 fn deserialize_vertex_properties<T>(
@@ -401,6 +683,51 @@ where
     }
 }
 
+fn deserialize_instructions<T>(
+    reader: &T,
+    instructions: &Value,
+) -> GremlinResult<Vec<(String, Vec<GValue>)>>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    match instructions {
+        Value::Array(instructions) => instructions
+            .iter()
+            .map(|instruction| {
+                let instruction = get_value!(instruction, Value::Array)?;
+                let operator = instruction
+                    .first()
+                    .ok_or_else(|| GremlinError::Json(String::from("Empty instruction")))?;
+                let operator = get_value!(operator, Value::String)?.clone();
+                let args = instruction[1..]
+                    .iter()
+                    .map(|arg| reader(arg).map(instruction_argument))
+                    .collect::<GremlinResult<Vec<GValue>>>()?;
+                Ok((operator, args))
+            })
+            .collect(),
+        Value::Null => Ok(vec![]),
+        _ => Err(GremlinError::Json(format!(
+            "Expected array or null for instructions. Found {}",
+            instructions
+        ))),
+    }
+}
+
+// `g:T` is read as a Token, but in instruction arguments it always stands for a T
+fn instruction_argument(arg: GValue) -> GValue {
+    match arg {
+        GValue::Token(token) => match token.value().as_str() {
+            "id" => GValue::T(T::Id),
+            "key" => GValue::T(T::Key),
+            "label" => GValue::T(T::Label),
+            "value" => GValue::T(T::Value),
+            _ => GValue::Token(token),
+        },
+        _ => arg,
+    }
+}
+
 fn remove_or_else(map: &mut Map, field: &str, owner: &str) -> GremlinResult<GValue> {
     remove(map, field, owner)
         .ok_or_else(|| GremlinError::Json(format!("Field {} not found in {}", field, owner)))
@@ -414,11 +741,16 @@ fn remove(map: &mut Map, field: &str, _owner: &str) -> Option<GValue> {
 mod tests {
 
     use super::deserializer_v2;
+    use crate::process::traversal::{Barrier, Merge, Operator, Order, Pick, Scope, __, DT};
+    use crate::structure::{Binding, Cardinality, Column, Direction, Lambda, Pop, TextP, P, T};
+    use crate::{GraphSON, GremlinError};
     use serde_json::json;
 
     use crate::{edge, vertex};
 
-    use crate::structure::{GValue, Map, Path, Property, Token, Vertex, VertexProperty, GID};
+    use crate::structure::{
+        Branch, GKey, GValue, Map, Path, Property, Token, Tree, Vertex, VertexProperty, GID,
+    };
     use chrono::offset::TimeZone;
     use std::collections::HashMap;
 
@@ -603,6 +935,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tree() {
+        let value = json!({"@type":"g:Tree","@value":[
+            {"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":1},"label":"person"}},
+             "value":{"@type":"g:Tree","@value":[
+                {"key":"josh","value":{"@type":"g:Tree","@value":[]}}]}}]});
+
+        let result = deserializer_v2(&value).expect("Failed to deserialize a Tree");
+
+        let tree = Tree::new(vec![Branch::new(
+            vertex!({ id => 1, label => "person", properties => {}}),
+            Tree::new(vec![Branch::new("josh", Tree::default())]),
+        )]);
+        assert_eq!(result, tree.into());
+    }
+
     #[test]
     fn test_path() {
         let value = json!({"@type":"g:Path","@value":{"labels":[ [], [], [] ], "objects":[{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":1},"label":"person"}},{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":10},"label":"software"}},{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":11},"label":"software"}}]}});
@@ -656,4 +1004,69 @@ mod tests {
 
         assert_eq!(result, GValue::Map(value_map));
     }
+
+    #[test]
+    fn test_bytecode() {
+        let g = crate::process::traversal::traversal().empty();
+
+        let code = g
+            .with_side_effect(("a", 1))
+            .v(())
+            .has_label(T::Label)
+            .has(("age", P::within((27, 32))))
+            .has(("name", TextP::starting_with("ma")))
+            .property_with_cardinality(Cardinality::List, "city", "rome")
+            .order(Scope::Local)
+            .by(("name", Order::Desc))
+            .local(__.out(()))
+            .filter(Lambda::new("it.get().value('age') > 30"))
+            .select((Pop::Last, "a"))
+            .merge_v(__.out(()))
+            .option((Merge::OnCreate, __.out(())))
+            .values("born")
+            .date_add(DT::Hour, 1)
+            .to_e(Direction::Out, ())
+            .sack_with(Operator::Mult)
+            .barrier_with(Barrier::NormSack)
+            .option((Pick::Any, __.out(())))
+            .select(Column::Values)
+            .has(("code", Binding::new("x", 1)))
+            .bytecode()
+            .clone();
+
+        let value = GraphSON::V2
+            .write(&code.clone().into())
+            .expect("Failed to serialize Bytecode");
+
+        let result = deserializer_v2(&value).expect("Failed to deserialize Bytecode");
+
+        assert_eq!(result, GValue::Bytecode(code));
+    }
+    #[test]
+    fn test_merge_with_token_keys() {
+        let g = crate::process::traversal::traversal().empty();
+
+        let vertex: HashMap<GKey, GValue> = vec![
+            (GKey::from(T::Label), GValue::from("person")),
+            (GKey::from("name"), GValue::from("marko")),
+        ]
+        .into_iter()
+        .collect();
+        let edge: HashMap<GKey, GValue> = vec![
+            (GKey::from(Direction::Out), GValue::from(1)),
+            (GKey::from(Direction::In), GValue::from(2)),
+        ]
+        .into_iter()
+        .collect();
+
+        for code in &[g.merge_v(vertex).bytecode(), g.merge_e(edge).bytecode()] {
+            let value = GValue::from((*code).clone());
+
+            match GraphSON::V2.write(&value) {
+                Err(GremlinError::Generic(msg)) => assert!(msg.contains("requires GraphSON V3")),
+                result => panic!("Unexpected result {:?}", result),
+            }
+            assert!(GraphSON::V3.write(&value).is_ok());
+        }
+    }
 }
@@ -2,9 +2,11 @@
 //!
 
 use crate::conversion::FromGValue;
+use crate::process::traversal::{Barrier, Bytecode, Merge, Operator, Order, Pick, Scope, DT};
 use crate::structure::{
-    Edge, GKey, GValue, IntermediateRepr, List, Map, Metric, Path, Property, Token,
-    TraversalExplanation, TraversalMetrics, Traverser, Vertex, VertexProperty, GID,
+    Binding, Branch, Cardinality, Column, Direction, Edge, GKey, GValue, IntermediateRepr, Lambda,
+    List, Map, Metric, Path, Pop, Property, TextP, Token, TraversalExplanation, TraversalMetrics,
+    Traverser, Tree, Vertex, VertexProperty, GID, P, T,
 };
 use crate::GremlinError;
 use crate::GremlinResult;
@@ -27,10 +29,14 @@ where
             GValue::String(d) => Ok(GID::String(d)),
             GValue::Int32(d) => Ok(GID::Int32(d)),
             GValue::Int64(d) => Ok(GID::Int64(d)),
-            _ => Err(GremlinError::Json(format!("{} cannot be an id", val))),
+            GValue::Uuid(d) => Ok(GID::Uuid(d)),
+            other => Ok(GID::Custom(Box::new(other))),
         },
         Err(e) => match e {
-            GremlinError::Json(_e) => Ok(GID::String(val.to_string())),
+            GremlinError::Json(_e) => Ok(match super::read_custom(val) {
+                Some(custom) => GID::Custom(Box::new(GValue::Custom(custom))),
+                None => GID::String(val.to_string()),
+            }),
             _ => Err(e),
         },
     }
@@ -194,6 +200,46 @@ where
     Ok(Path::new(labels, objects).into())
 }
 
+// Tree deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_tree)
+pub fn deserialize_tree<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let val = get_value!(val, Value::Array)?;
+    let mut branches = Vec::with_capacity(val.len());
+    for item in val {
+        let key = reader(&item["key"])?;
+        let tree = reader(&item["value"])?.take::<Tree>()?;
+        branches.push(Branch::new(key, tree));
+    }
+    Ok(Tree::new(branches).into())
+}
+
+// TinkerGraph deserializer, the result of a `subgraph()` step
+pub fn deserialize_tinker_graph<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let mut graph = HashMap::new();
+    for key in &["vertices", "edges"] {
+        let elements = match &val[*key] {
+            Value::Array(items) => items
+                .iter()
+                .map(reader)
+                .collect::<GremlinResult<Vec<_>>>()?,
+            Value::Null => vec![],
+            _ => {
+                return Err(GremlinError::Json(format!(
+                    "Value {:?} is not a valid tinker:graph",
+                    val
+                )))
+            }
+        };
+        graph.insert(String::from(*key), GValue::List(elements.into()));
+    }
+    Ok(Map::from(graph).into())
+}
+
 // Traversal Metrics deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_traversalmetrics)
 pub fn deserialize_metrics<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
 where
@@ -254,6 +300,7 @@ where
         perc_duration,
         nested?,
     )
+    .with_annotations(annotations)
     .into())
 }
 
@@ -348,6 +395,223 @@ where
     Ok(Traverser::new(bulk, v).into())
 }
 
+// Bytecode deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_bytecode_2)
+pub fn deserialize_bytecode<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let mut code = Bytecode::new();
+
+    for (operator, args) in deserialize_instructions(reader, &val["source"])? {
+        code.add_source(operator, args);
+    }
+    for (operator, args) in deserialize_instructions(reader, &val["step"])? {
+        code.add_step(operator, args);
+    }
+    Ok(code.into())
+}
+
+// P deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_p_2)
+pub fn deserialize_p<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = get_value!(&val["predicate"], Value::String)?;
+    let value = reader(&val["value"])?;
+    Ok(P::new(operator.clone(), value).into())
+}
+
+// TextP deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_textp_2)
+pub fn deserialize_text_p<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = get_value!(&val["predicate"], Value::String)?;
+    let value = reader(&val["value"])?;
+    Ok(TextP::new(operator.clone(), value).into())
+}
+
+// Lambda deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_lambda_2)
+pub fn deserialize_lambda<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let script = get_value!(&val["script"], Value::String)?;
+    let language = val
+        .get("language")
+        .map(|f| get_value!(f, Value::String).map(Clone::clone))
+        .unwrap_or_else(|| Ok(String::from("gremlin-groovy")))?;
+    let arguments = val.get("arguments").and_then(Value::as_i64).unwrap_or(-1);
+
+    Ok(Lambda::with_language(script.clone(), language)
+        .with_arguments(arguments as i32)
+        .into())
+}
+
+// Binding deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_binding_2)
+pub fn deserialize_binding<T>(reader: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let key = get_value!(&val["key"], Value::String)?;
+    let value = reader(&val["value"])?;
+
+    Ok(Binding::new(key.clone(), value).into())
+}
+
+// Order deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_order_2)
+pub fn deserialize_order<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let order = match get_value!(val, Value::String)?.as_str() {
+        "asc" | "incr" => Order::Asc,
+        "desc" | "decr" => Order::Desc,
+        "shuffle" => Order::Shuffle,
+        other => return Err(GremlinError::Json(format!("Unknown Order {}", other))),
+    };
+    Ok(order.into())
+}
+
+// Scope deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_scope_2)
+pub fn deserialize_scope<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let scope = match get_value!(val, Value::String)?.as_str() {
+        "global" => Scope::Global,
+        "local" => Scope::Local,
+        other => return Err(GremlinError::Json(format!("Unknown Scope {}", other))),
+    };
+    Ok(scope.into())
+}
+
+// Pop deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_pop_2)
+pub fn deserialize_pop<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let pop = match get_value!(val, Value::String)?.as_str() {
+        "all" => Pop::All,
+        "first" => Pop::First,
+        "last" => Pop::Last,
+        "mixed" => Pop::Mixed,
+        other => return Err(GremlinError::Json(format!("Unknown Pop {}", other))),
+    };
+    Ok(pop.into())
+}
+
+// Cardinality deserializer [docs](http://tinkerpop.apache.org/docs/current/dev/io/#_cardinality_2)
+pub fn deserialize_cardinality<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let cardinality = match get_value!(val, Value::String)?.as_str() {
+        "list" => Cardinality::List,
+        "set" => Cardinality::Set,
+        "single" => Cardinality::Single,
+        other => return Err(GremlinError::Json(format!("Unknown Cardinality {}", other))),
+    };
+    Ok(cardinality.into())
+}
+
+pub fn deserialize_direction<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let direction = match get_value!(val, Value::String)?.as_str() {
+        "OUT" => Direction::Out,
+        "IN" => Direction::In,
+        "BOTH" => Direction::Both,
+        other => return Err(GremlinError::Json(format!("Unknown Direction {}", other))),
+    };
+    Ok(direction.into())
+}
+
+pub fn deserialize_merge<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let merge = match get_value!(val, Value::String)?.as_str() {
+        "onCreate" => Merge::OnCreate,
+        "onMatch" => Merge::OnMatch,
+        "outV" => Merge::OutV,
+        "inV" => Merge::InV,
+        other => return Err(GremlinError::Json(format!("Unknown Merge {}", other))),
+    };
+    Ok(merge.into())
+}
+
+pub fn deserialize_dt<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let dt = match get_value!(val, Value::String)?.as_str() {
+        "second" => DT::Second,
+        "minute" => DT::Minute,
+        "hour" => DT::Hour,
+        "day" => DT::Day,
+        other => return Err(GremlinError::Json(format!("Unknown DT {}", other))),
+    };
+    Ok(dt.into())
+}
+
+pub fn deserialize_column<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let column = match get_value!(val, Value::String)?.as_str() {
+        "keys" => Column::Keys,
+        "values" => Column::Values,
+        other => return Err(GremlinError::Json(format!("Unknown Column {}", other))),
+    };
+    Ok(column.into())
+}
+
+pub fn deserialize_operator<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let operator = match get_value!(val, Value::String)?.as_str() {
+        "sum" => Operator::Sum,
+        "minus" => Operator::Minus,
+        "mult" => Operator::Mult,
+        "div" => Operator::Div,
+        "min" => Operator::Min,
+        "max" => Operator::Max,
+        "assign" => Operator::Assign,
+        "and" => Operator::And,
+        "or" => Operator::Or,
+        "addAll" => Operator::AddAll,
+        "sumLong" => Operator::SumLong,
+        other => return Err(GremlinError::Json(format!("Unknown Operator {}", other))),
+    };
+    Ok(operator.into())
+}
+
+pub fn deserialize_barrier<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let barrier = match get_value!(val, Value::String)?.as_str() {
+        "normSack" => Barrier::NormSack,
+        other => return Err(GremlinError::Json(format!("Unknown Barrier {}", other))),
+    };
+    Ok(barrier.into())
+}
+
+pub fn deserialize_pick<T>(_: &T, val: &Value) -> GremlinResult<GValue>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    let pick = match get_value!(val, Value::String)?.as_str() {
+        "any" => Pick::Any,
+        "none" => Pick::None,
+        other => return Err(GremlinError::Json(format!("Unknown Pick {}", other))),
+    };
+    Ok(pick.into())
+}
+
 // deserialzer v3
 g_serializer!(deserializer_v3, {
     "g:Int32" => deserialize_g32,
@@ -365,10 +629,28 @@ g_serializer!(deserializer_v3, {
     "g:Property" => deserialize_property,
     "g:Edge" => deserialize_edge,
     "g:Path" => deserialize_path,
+    "g:Tree" => deserialize_tree,
+    "tinker:graph" => deserialize_tinker_graph,
     "g:TraversalMetrics" => deserialize_metrics,
     "g:Metrics" => deserialize_metric,
     "g:TraversalExplanation" => deserialize_explain,
-    "g:Traverser" => deserialize_traverser
+    "g:Traverser" => deserialize_traverser,
+    "g:Bytecode" => deserialize_bytecode,
+    "g:P" => deserialize_p,
+    "g:TextP" => deserialize_text_p,
+    "g:Order" => deserialize_order,
+    "g:Scope" => deserialize_scope,
+    "g:Pop" => deserialize_pop,
+    "g:Cardinality" => deserialize_cardinality,
+    "g:Direction" => deserialize_direction,
+    "g:Merge" => deserialize_merge,
+    "g:DT" => deserialize_dt,
+    "g:Column" => deserialize_column,
+    "g:Operator" => deserialize_operator,
+    "g:Barrier" => deserialize_barrier,
+    "g:Pick" => deserialize_pick,
+    "g:Lambda" => deserialize_lambda,
+    "g:Binding" => deserialize_binding
 });
 
 fn deserialize_vertex_properties<T>(
@@ -409,6 +691,51 @@ where
     }
 }
 
+fn deserialize_instructions<T>(
+    reader: &T,
+    instructions: &Value,
+) -> GremlinResult<Vec<(String, Vec<GValue>)>>
+where
+    T: Fn(&Value) -> GremlinResult<GValue>,
+{
+    match instructions {
+        Value::Array(instructions) => instructions
+            .iter()
+            .map(|instruction| {
+                let instruction = get_value!(instruction, Value::Array)?;
+                let operator = instruction
+                    .first()
+                    .ok_or_else(|| GremlinError::Json(String::from("Empty instruction")))?;
+                let operator = get_value!(operator, Value::String)?.clone();
+                let args = instruction[1..]
+                    .iter()
+                    .map(|arg| reader(arg).map(instruction_argument))
+                    .collect::<GremlinResult<Vec<GValue>>>()?;
+                Ok((operator, args))
+            })
+            .collect(),
+        Value::Null => Ok(vec![]),
+        _ => Err(GremlinError::Json(format!(
+            "Expected array or null for instructions. Found {}",
+            instructions
+        ))),
+    }
+}
+
+// `g:T` is read as a Token, but in instruction arguments it always stands for a T
+fn instruction_argument(arg: GValue) -> GValue {
+    match arg {
+        GValue::Token(token) => match token.value().as_str() {
+            "id" => GValue::T(T::Id),
+            "key" => GValue::T(T::Key),
+            "label" => GValue::T(T::Label),
+            "value" => GValue::T(T::Value),
+            _ => GValue::Token(token),
+        },
+        _ => arg,
+    }
+}
+
 fn remove_or_else(map: &mut Map, field: &str, owner: &str) -> GremlinResult<GValue> {
     remove(map, field, owner)
         .ok_or_else(|| GremlinError::Json(format!("Field {} not found in {}", field, owner)))
@@ -422,12 +749,18 @@ fn remove(map: &mut Map, field: &str, _owner: &str) -> Option<GValue> {
 mod tests {
 
     use super::deserializer_v3;
+    use crate::process::traversal::{Barrier, Merge, Operator, Order, Pick, Scope, __, DT};
+    use crate::structure::{
+        Binding, Cardinality, Column, Direction, GKey, Lambda, Pop, TextP, P, T,
+    };
+    use crate::GraphSON;
     use serde_json::json;
 
     use crate::{edge, vertex};
 
     use crate::structure::{
-        GValue, Map, Metric, Path, Property, Token, TraversalMetrics, Vertex, VertexProperty, GID,
+        Branch, GValue, Map, Metric, Path, Property, Token, TraversalMetrics, Tree, Vertex,
+        VertexProperty, GID,
     };
     use chrono::offset::TimeZone;
     use std::collections::HashMap;
@@ -653,6 +986,65 @@ mod tests {
         assert_eq!(result, path.into());
     }
 
+    #[test]
+    fn test_tree() {
+        let value = json!({"@type":"g:Tree","@value":[
+            {"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":1},"label":"person"}},
+             "value":{"@type":"g:Tree","@value":[
+                {"key":{"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":4},"label":"person"}},
+                 "value":{"@type":"g:Tree","@value":[]}}]}}]});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Tree");
+
+        let tree = Tree::new(vec![Branch::new(
+            vertex!({ id => 1, label => "person", properties => {}}),
+            Tree::new(vec![Branch::new(
+                vertex!({ id => 4, label => "person", properties => {}}),
+                Tree::default(),
+            )]),
+        )]);
+        assert_eq!(result, tree.into());
+    }
+
+    #[test]
+    fn test_tinker_graph() {
+        let value = json!({"@type":"tinker:graph","@value":{
+            "vertices":[
+                {"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":1},"label":"person"}},
+                {"@type":"g:Vertex","@value":{"id":{"@type":"g:Int32","@value":10},"label":"software"}}],
+            "edges":[
+                {"@type":"g:Edge","@value":{"id":{"@type":"g:Int32","@value":13},"label":"develops","inVLabel":"software","outVLabel":"person","inV":{"@type":"g:Int32","@value":10},"outV":{"@type":"g:Int32","@value":1}}}]}});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a tinker:graph");
+
+        let mut graph = HashMap::new();
+        graph.insert(
+            String::from("vertices"),
+            GValue::List(
+                vec![
+                    vertex!({ id => 1, label => "person", properties => {}}).into(),
+                    vertex!({ id => 10, label => "software", properties => {}}).into(),
+                ]
+                .into(),
+            ),
+        );
+        graph.insert(
+            String::from("edges"),
+            GValue::List(
+                vec![edge!({
+                    id => 13,
+                    label => "develops",
+                    inV => { id => 10, label => "software" },
+                    outV => { id => 1, label => "person" },
+                    properties => {}
+                })
+                .into()]
+                .into(),
+            ),
+        );
+        assert_eq!(result, Map::from(graph).into());
+    }
+
     #[test]
     fn test_traversal_metrics() {
         let value = serde_json::from_str(r#"{"@type":"g:TraversalMetrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":0.004},"metrics",{"@type":"g:List","@value":[{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":100.0},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":4},"elementCount",{"@type":"g:Int64","@value":4}]},"name","TinkerGraphStep(vertex,[~label.eq(person)])","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":25.0}]},"id","7.0.0()"]}},{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":100.0},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":13},"elementCount",{"@type":"g:Int64","@value":13}]},"name","VertexStep(OUT,vertex)","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":25.0}]},"id","2.0.0()"]}},{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":100.0},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":7},"elementCount",{"@type":"g:Int64","@value":7}]},"name","VertexStep(OUT,vertex)","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":25.0}]},"id","3.0.0()"]}},{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":100.0},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":1},"elementCount",{"@type":"g:Int64","@value":1}]},"name","TreeStep","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":25.0}]},"id","4.0.0()"]}}]}]}}"#).expect("Error parsing json");
@@ -696,6 +1088,57 @@ mod tests {
         assert_eq!(result, traversal_metrics.into());
     }
 
+    #[test]
+    fn test_traversal_metrics_nested_with_annotations() {
+        let value = serde_json::from_str(r#"{"@type":"g:TraversalMetrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":1.5},"metrics",{"@type":"g:List","@value":[{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":1.5},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":2},"elementCount",{"@type":"g:Int64","@value":2}]},"name","JanusGraphStep([],[name.eq(marko)])","annotations",{"@type":"g:Map","@value":["percentDur",{"@type":"g:Double","@value":100.0},"index","byName"]},"id","1.0.0()","metrics",{"@type":"g:List","@value":[{"@type":"g:Metrics","@value":{"@type":"g:Map","@value":["dur",{"@type":"g:Double","@value":0.5},"counts",{"@type":"g:Map","@value":["traverserCount",{"@type":"g:Int64","@value":2},"elementCount",{"@type":"g:Int64","@value":2}]},"name","backend-query","id","1.0.0().1"]}}]}]}}]}]}}"#).expect("Error parsing json");
+
+        let result = deserializer_v3(&value)
+            .expect("Failed to deserialize a TraversalMetrics")
+            .take::<TraversalMetrics>()
+            .expect("Failed to take a TraversalMetrics");
+
+        let annotations: Map = vec![(String::from("index"), GValue::from("byName"))]
+            .into_iter()
+            .collect();
+
+        let expected = TraversalMetrics::new(
+            1.5,
+            vec![Metric::new(
+                "1.0.0()",
+                "JanusGraphStep([],[name.eq(marko)])",
+                1.5,
+                2,
+                2,
+                100.0,
+                vec![Metric::new(
+                    "1.0.0().1",
+                    "backend-query",
+                    0.5,
+                    2,
+                    2,
+                    0.0,
+                    vec![],
+                )],
+            )
+            .with_annotations(annotations)],
+        );
+
+        assert_eq!(result, expected);
+
+        let rendered = vec![
+            "Traversal Metrics",
+            "Step                                                             Count  Traversers       Time (ms)    % Dur",
+            "===========================================================================================================",
+            "JanusGraphStep([],[name.eq(marko)])                                  2           2           1.500   100.00",
+            "  \\_index=byName",
+            "  backend-query                                                      2           2           0.500     0.00",
+            ">TOTAL                                                               -           -           1.500        -",
+        ]
+        .join("\n");
+
+        assert_eq!(format!("{}", result), rendered);
+    }
+
     #[test]
     fn test_token() {
         let value = json!({
@@ -734,4 +1177,92 @@ mod tests {
 
         assert_eq!(result, GValue::Map(value_map));
     }
+
+    #[test]
+    fn test_bytecode() {
+        let g = crate::process::traversal::traversal().empty();
+
+        let code = g
+            .with_side_effect(("a", 1))
+            .v(())
+            .has_label(T::Label)
+            .has(("age", P::within((27, 32))))
+            .has(("name", TextP::starting_with("ma")))
+            .property_with_cardinality(Cardinality::List, "city", "rome")
+            .order(Scope::Local)
+            .by(("name", Order::Desc))
+            .local(__.out(()))
+            .filter(Lambda::new("it.get().value('age') > 30"))
+            .select((Pop::Last, "a"))
+            .merge_e(
+                vec![
+                    (GKey::from(Direction::Out), GValue::from(1)),
+                    (GKey::from(Direction::In), GValue::from(2)),
+                ]
+                .into_iter()
+                .collect::<HashMap<GKey, GValue>>(),
+            )
+            .option((Merge::OnCreate, __.out(())))
+            .values("born")
+            .date_add(DT::Hour, 1)
+            .to_e(Direction::Out, ())
+            .sack_with(Operator::Mult)
+            .barrier_with(Barrier::NormSack)
+            .option((Pick::Any, __.out(())))
+            .select(Column::Values)
+            .has(("code", Binding::new("x", 1)))
+            .bytecode()
+            .clone();
+
+        let value = GraphSON::V3
+            .write(&code.clone().into())
+            .expect("Failed to serialize Bytecode");
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize Bytecode");
+
+        assert_eq!(result, GValue::Bytecode(code));
+    }
+
+    #[test]
+    fn test_map_with_non_string_keys() {
+        let value = json!({"@type": "g:Map",
+                        "@value": [{"@type": "g:Int32", "@value": 29}, {"@type": "g:Int64", "@value": 2},
+                                   {"@type": "g:Double", "@value": 0.4}, {"@type": "g:Int64", "@value": 1},
+                                   {"@type": "g:List", "@value": ["a", {"@type": "g:Int32", "@value": 1}]}, "list"]});
+
+        let result = deserializer_v3(&value).expect("Failed to deserialize a Map");
+
+        let map = result.clone().take::<Map>().unwrap();
+        assert_eq!(&GValue::Int64(2), &map[29]);
+        assert_eq!(&GValue::Int64(1), &map[0.4]);
+        assert_eq!(
+            &GValue::from("list"),
+            &map[vec![GKey::from("a"), GKey::from(1)]]
+        );
+
+        let uuid = uuid::Uuid::parse_str("41d2e28a-20a4-4ab0-b379-d810dede3786").unwrap();
+        let date = chrono::Utc.timestamp_millis(1481750076295);
+
+        let mut keys = HashMap::new();
+        keys.insert(GKey::from(uuid), GValue::from(1));
+        keys.insert(GKey::from(date), GValue::from(2));
+        keys.insert(GKey::from(true), GValue::from(3));
+        keys.insert(GKey::from(Property::new("since", 2009)), GValue::from(4));
+        keys.insert(
+            GKey::from(VertexProperty::new(GID::Int64(1), "name", "marko")),
+            GValue::from(5),
+        );
+        keys.insert(GKey::from(1.5f32), GValue::from(6));
+
+        for value in vec![result, GValue::from(Map::from(keys))] {
+            let json = GraphSON::V3
+                .write(&value)
+                .expect("Failed to serialize a Map");
+
+            assert_eq!(
+                value,
+                deserializer_v3(&json).expect("Failed to deserialize a Map")
+            );
+        }
+    }
 }
@@ -0,0 +1,246 @@
+//! JanusGraph specific types.
+
+use crate::structure::{Custom, GValue, GID};
+use crate::{GremlinError, GremlinResult};
+use serde_json::json;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// GraphSON type of JanusGraph edge ids
+pub const RELATION_IDENTIFIER: &str = "janusgraph:RelationIdentifier";
+
+const BASE: i64 = 36;
+const SYMBOLS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The id of a JanusGraph edge, e.g. `4r6-39s-69zp-3c8`.
+///
+/// It's made of the relation id, the out vertex id, the edge label id and, when present,
+/// the in vertex id, each one base 36 encoded. Converting it into a [GID](../enum.GID.html)
+/// gives an id that can be passed to `g.E(id)`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct RelationIdentifier {
+    relation_id: i64,
+    out_vertex_id: i64,
+    type_id: i64,
+    in_vertex_id: Option<i64>,
+}
+
+impl RelationIdentifier {
+    pub fn new(
+        relation_id: i64,
+        out_vertex_id: i64,
+        type_id: i64,
+        in_vertex_id: Option<i64>,
+    ) -> RelationIdentifier {
+        RelationIdentifier {
+            relation_id,
+            out_vertex_id,
+            type_id,
+            in_vertex_id,
+        }
+    }
+
+    pub fn relation_id(&self) -> i64 {
+        self.relation_id
+    }
+
+    pub fn out_vertex_id(&self) -> i64 {
+        self.out_vertex_id
+    }
+
+    pub fn type_id(&self) -> i64 {
+        self.type_id
+    }
+
+    pub fn in_vertex_id(&self) -> Option<i64> {
+        self.in_vertex_id
+    }
+}
+
+fn decode(part: &str, id: &str) -> GremlinResult<i64> {
+    match i64::from_str_radix(part, BASE as u32) {
+        Ok(n) if n >= 0 && part.bytes().all(|b| SYMBOLS.contains(&b)) => Ok(n),
+        _ => Err(GremlinError::Cast(format!(
+            "{} is not a valid relation identifier",
+            id
+        ))),
+    }
+}
+
+fn encode(mut n: i64, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut digits = vec![];
+    loop {
+        digits.push(SYMBOLS[(n % BASE) as usize]);
+        n /= BASE;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    f.write_str(&String::from_utf8_lossy(&digits))
+}
+
+impl FromStr for RelationIdentifier {
+    type Err = GremlinError;
+
+    fn from_str(id: &str) -> GremlinResult<RelationIdentifier> {
+        let parts = id.split('-').collect::<Vec<_>>();
+
+        match parts.as_slice() {
+            [relation, out_v, type_id] => Ok(RelationIdentifier::new(
+                decode(relation, id)?,
+                decode(out_v, id)?,
+                decode(type_id, id)?,
+                None,
+            )),
+            [relation, out_v, type_id, in_v] => Ok(RelationIdentifier::new(
+                decode(relation, id)?,
+                decode(out_v, id)?,
+                decode(type_id, id)?,
+                Some(decode(in_v, id)?),
+            )),
+            _ => Err(GremlinError::Cast(format!(
+                "{} is not a valid relation identifier",
+                id
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for RelationIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        encode(self.relation_id, f)?;
+        f.write_str("-")?;
+        encode(self.out_vertex_id, f)?;
+        f.write_str("-")?;
+        encode(self.type_id, f)?;
+        if let Some(in_vertex_id) = self.in_vertex_id {
+            f.write_str("-")?;
+            encode(in_vertex_id, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<RelationIdentifier> for GID {
+    fn from(val: RelationIdentifier) -> GID {
+        GID::Custom(Box::new(GValue::Custom(Custom::new(
+            RELATION_IDENTIFIER,
+            json!({ "relationId": val.to_string() }),
+        ))))
+    }
+}
+
+impl TryFrom<&GID> for RelationIdentifier {
+    type Error = GremlinError;
+
+    fn try_from(id: &GID) -> GremlinResult<RelationIdentifier> {
+        match id {
+            GID::Custom(value) => match value.as_ref() {
+                GValue::Custom(custom) if custom.type_name() == RELATION_IDENTIFIER => {
+                    match custom.value()["relationId"].as_str() {
+                        Some(relation_id) => relation_id.parse(),
+                        None => Err(GremlinError::Cast(format!(
+                            "Missing relationId in {:?}",
+                            custom
+                        ))),
+                    }
+                }
+                _ => Err(GremlinError::Cast(format!(
+                    "Cannot convert {:?} to a RelationIdentifier",
+                    id
+                ))),
+            },
+            GID::String(s) => s.parse(),
+            _ => Err(GremlinError::Cast(format!(
+                "Cannot convert {:?} to a RelationIdentifier",
+                id
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelationIdentifier, RELATION_IDENTIFIER};
+    use crate::io::GraphSON;
+    use crate::process::traversal::traversal;
+    use crate::structure::{Edge, GValue, GID};
+    use crate::ToGValue;
+    use serde_json::json;
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn parse_and_display_test() {
+        let id: RelationIdentifier = "4r6-39s-69zp-3c8".parse().unwrap();
+
+        assert_eq!(6162, id.relation_id());
+        assert_eq!(4240, id.out_vertex_id());
+        assert_eq!(292_885, id.type_id());
+        assert_eq!(Some(4328), id.in_vertex_id());
+        assert_eq!("4r6-39s-69zp-3c8", id.to_string());
+
+        let id: RelationIdentifier = "0-1-z".parse().unwrap();
+        assert_eq!(RelationIdentifier::new(0, 1, 35, None), id);
+        assert_eq!("0-1-z", id.to_string());
+
+        assert!("4r6-39s".parse::<RelationIdentifier>().is_err());
+        assert!("4r6-+39s-69zp".parse::<RelationIdentifier>().is_err());
+        assert!("4r6-39s-69zp-3c8-1".parse::<RelationIdentifier>().is_err());
+    }
+
+    #[test]
+    fn relation_identifier_round_trip_test() {
+        let raw_id = json!({
+            "@type": RELATION_IDENTIFIER,
+            "@value": { "relationId": "4r6-39s-69zp-3c8" }
+        });
+
+        let value = json!({
+            "@type": "g:Edge",
+            "@value": {
+                "id": raw_id,
+                "label": "knows",
+                "inVLabel": "person",
+                "outVLabel": "person",
+                "inV": { "@type": "g:Int64", "@value": 4328 },
+                "outV": { "@type": "g:Int64", "@value": 4240 }
+            }
+        });
+
+        let edge = GraphSON::V3
+            .read(&value)
+            .unwrap()
+            .unwrap()
+            .take::<Edge>()
+            .unwrap();
+
+        let id = RelationIdentifier::try_from(edge.id()).unwrap();
+        assert_eq!("4r6-39s-69zp-3c8", id.to_string());
+        assert_eq!(edge.id(), &GID::from(id));
+
+        assert_eq!(raw_id, GraphSON::V3.write(&edge.id().to_gvalue()).unwrap());
+
+        let g = traversal().empty();
+        let bytecode = g.e(id).bytecode().clone();
+
+        assert_eq!(&vec![edge.id().to_gvalue()], bytecode.steps()[0].args());
+        assert!(matches!(edge.id(), GID::Custom(value) if matches!(**value, GValue::Custom(_))));
+    }
+
+    #[test]
+    fn relation_identifier_as_key_test() {
+        let id: RelationIdentifier = "4r6-39s-69zp-3c8".parse().unwrap();
+
+        let mut ids = HashSet::new();
+        ids.insert(GID::from(id));
+        ids.insert(GID::from(RelationIdentifier::new(0, 1, 35, None)));
+
+        assert_eq!(2, ids.len());
+        assert!(ids.contains(&GID::from(id)));
+        assert_eq!("4r6-39s-69zp-3c8", GID::from(id).to_string());
+        assert!(!ids.contains(&GID::from(RelationIdentifier::new(0, 1, 36, None))));
+    }
+}
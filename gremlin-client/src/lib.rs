@@ -116,34 +116,51 @@ extern crate lazy_static;
 mod client;
 mod connection;
 mod conversion;
+mod de;
 mod error;
 mod io;
 mod message;
 mod pool;
+mod ser;
 
 pub use client::GremlinClient;
 pub use connection::{ConnectionOptions, TlsOptions};
 pub use conversion::{BorrowFromGValue, FromGValue, ToGValue};
+pub use de::from_gvalue;
 pub use error::GremlinError;
 pub use io::GraphSON;
 pub use message::Message;
+pub use ser::to_gvalue;
 
 pub type GremlinResult<T> = Result<T, error::GremlinError>;
 
 pub use structure::{
-    Cardinality, Edge, GKey, GResultSet, GValue, IntermediateRepr, List, Map, Metric, Path,
-    Property, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty, GID,
+    Cardinality, Custom, Edge, GKey, GResultSet, GValue, IntermediateRepr, Lenient, List, Map,
+    Metric, Path, Property, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty,
+    GID,
 };
 
 #[cfg(feature = "async_gremlin")]
 pub mod aio;
 
+#[cfg(feature = "embedded")]
+pub mod embedded;
+
+#[cfg(feature = "petgraph")]
+pub mod graph;
+
+pub mod janusgraph;
 pub mod process;
 pub mod structure;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utils;
 
 #[cfg(feature = "derive")]
 pub mod derive {
+    pub use gremlin_derive::gremlin_dsl;
     pub use gremlin_derive::FromGMap;
     pub use gremlin_derive::FromGValue;
+    pub use gremlin_derive::GremlinVertex;
+    pub use gremlin_derive::ToGValue;
 }
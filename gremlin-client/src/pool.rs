@@ -27,8 +27,8 @@ impl ManageConnection for GremlinConnectionManager {
         Ok(())
     }
 
-    fn has_broken(&self, _conn: &mut Connection) -> bool {
-        false
+    fn has_broken(&self, conn: &mut Connection) -> bool {
+        conn.is_broken()
     }
 }
 
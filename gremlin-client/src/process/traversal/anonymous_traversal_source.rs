@@ -1,13 +1,23 @@
+use crate::process::traversal::step::by::ByStep;
+use crate::process::traversal::step::collection::CollectionStep;
+use crate::process::traversal::step::concat::ConcatStep;
+use crate::process::traversal::step::date_diff::DateDiffStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::loops::LoopsStep;
+use crate::process::traversal::step::merge_step::MergeStep;
 use crate::process::traversal::step::not::NotStep;
 use crate::process::traversal::step::or::OrStep;
 use crate::process::traversal::step::repeat::RepeatStep;
+use crate::process::traversal::step::sack::SackStep;
 use crate::process::traversal::step::select::SelectStep;
+use crate::process::traversal::step::side_effect::SideEffectStep;
+use crate::process::traversal::step::substring::SubstringStep;
 use crate::process::traversal::step::until::UntilStep;
 use crate::process::traversal::step::where_step::WhereStep;
-use crate::process::traversal::TraversalBuilder;
-use crate::structure::{Either2, GIDs, IntoPredicate, Labels, T};
+use crate::process::traversal::{Scope, TraversalBuilder, DT};
+use crate::structure::{Direction, Either2, GIDs, IntoPredicate, Labels, T};
 use crate::GValue;
 
 pub struct AnonymousTraversalSource {
@@ -53,6 +63,20 @@ impl AnonymousTraversalSource {
         self.traversal.clone().v(ids)
     }
 
+    pub fn merge_v<A>(&self, criteria: A) -> TraversalBuilder
+    where
+        A: Into<MergeStep>,
+    {
+        self.traversal.clone().merge_v(criteria)
+    }
+
+    pub fn merge_e<A>(&self, criteria: A) -> TraversalBuilder
+    where
+        A: Into<MergeStep>,
+    {
+        self.traversal.clone().merge_e(criteria)
+    }
+
     pub fn add_e<A>(&self, label: A) -> TraversalBuilder
     where
         A: Into<String>,
@@ -71,6 +95,17 @@ impl AnonymousTraversalSource {
         self.traversal.clone().out(labels)
     }
 
+    pub fn to_v(&self, direction: Direction) -> TraversalBuilder {
+        self.traversal.clone().to_v(direction)
+    }
+
+    pub fn to_e<L>(&self, direction: Direction, labels: L) -> TraversalBuilder
+    where
+        L: Into<Labels>,
+    {
+        self.traversal.clone().to_e(direction, labels)
+    }
+
     pub fn out_e<L>(&self, labels: L) -> TraversalBuilder
     where
         L: Into<Labels>,
@@ -177,6 +212,10 @@ impl AnonymousTraversalSource {
         self.traversal.clone().unfold()
     }
 
+    pub fn tree(&self) -> TraversalBuilder {
+        self.traversal.clone().tree()
+    }
+
     pub fn out_v(&self) -> TraversalBuilder {
         self.traversal.clone().out_v()
     }
@@ -206,6 +245,10 @@ impl AnonymousTraversalSource {
         self.traversal.clone().cap(step)
     }
 
+    pub fn subgraph(&self, step: &'static str) -> TraversalBuilder {
+        self.traversal.clone().subgraph(step)
+    }
+
     pub fn project<A>(&self, step: A) -> TraversalBuilder
     where
         A: Into<SelectStep>,
@@ -237,6 +280,198 @@ impl AnonymousTraversalSource {
     pub fn emit(&self) -> TraversalBuilder {
         self.traversal.clone().emit()
     }
+
+    pub fn map<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<ByStep>,
+    {
+        self.traversal.clone().map(step)
+    }
+
+    pub fn filter<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FilterStep>,
+    {
+        self.traversal.clone().filter(step)
+    }
+
+    pub fn flat_map<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.traversal.clone().flat_map(step)
+    }
+
+    pub fn side_effect<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<SideEffectStep>,
+    {
+        self.traversal.clone().side_effect(step)
+    }
+
+    pub fn sack(&self) -> TraversalBuilder {
+        self.traversal.clone().sack()
+    }
+
+    pub fn sack_with<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<SackStep>,
+    {
+        self.traversal.clone().sack_with(step)
+    }
+
+    pub fn concat<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<ConcatStep>,
+    {
+        self.traversal.clone().concat(step)
+    }
+
+    pub fn as_string<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().as_string(scope)
+    }
+
+    pub fn to_upper<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().to_upper(scope)
+    }
+
+    pub fn to_lower<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().to_lower(scope)
+    }
+
+    pub fn trim<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().trim(scope)
+    }
+
+    pub fn l_trim<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().l_trim(scope)
+    }
+
+    pub fn r_trim<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().r_trim(scope)
+    }
+
+    pub fn replace<A, B>(&self, old: A, new: B) -> TraversalBuilder
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        self.traversal.clone().replace(old, new)
+    }
+
+    pub fn split<A>(&self, separator: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().split(separator)
+    }
+
+    pub fn substring<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<SubstringStep>,
+    {
+        self.traversal.clone().substring(step)
+    }
+
+    pub fn format<A>(&self, format: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().format(format)
+    }
+
+    pub fn as_date(&self) -> TraversalBuilder {
+        self.traversal.clone().as_date()
+    }
+
+    pub fn date_add(&self, unit: DT, value: i32) -> TraversalBuilder {
+        self.traversal.clone().date_add(unit, value)
+    }
+
+    pub fn date_diff<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<DateDiffStep>,
+    {
+        self.traversal.clone().date_diff(step)
+    }
+
+    pub fn length<A>(&self, scope: A) -> TraversalBuilder
+    where
+        A: Into<Scope>,
+    {
+        self.traversal.clone().length(scope)
+    }
+
+    pub fn reverse(&self) -> TraversalBuilder {
+        self.traversal.clone().reverse()
+    }
+
+    pub fn combine<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().combine(step)
+    }
+
+    pub fn difference<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().difference(step)
+    }
+
+    pub fn disjunct<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().disjunct(step)
+    }
+
+    pub fn intersect<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().intersect(step)
+    }
+
+    pub fn merge<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().merge(step)
+    }
+
+    pub fn product<A>(&self, step: A) -> TraversalBuilder
+    where
+        A: Into<CollectionStep>,
+    {
+        self.traversal.clone().product(step)
+    }
+
+    pub fn conjoin<A>(&self, delimiter: A) -> TraversalBuilder
+    where
+        A: Into<String>,
+    {
+        self.traversal.clone().conjoin(delimiter)
+    }
 }
 
 impl Default for AnonymousTraversalSource {
@@ -0,0 +1,14 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Barrier {
+    NormSack,
+}
+
+impl Display for Barrier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Barrier::NormSack => write!(f, "normSack"),
+        }
+    }
+}
@@ -2,23 +2,34 @@ use crate::conversion::ToGValue;
 use crate::process::traversal::step::by::ByStep;
 use crate::process::traversal::step::choose::IntoChooseStep;
 use crate::process::traversal::step::coalesce::CoalesceStep;
+use crate::process::traversal::step::collection::CollectionStep;
+use crate::process::traversal::step::concat::ConcatStep;
+use crate::process::traversal::step::date_diff::DateDiffStep;
 use crate::process::traversal::step::dedup::DedupStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::from::FromStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::limit::LimitStep;
 use crate::process::traversal::step::local::LocalStep;
 use crate::process::traversal::step::loops::LoopsStep;
 use crate::process::traversal::step::match_step::MatchStep;
+use crate::process::traversal::step::merge_step::MergeStep;
 use crate::process::traversal::step::not::NotStep;
+use crate::process::traversal::step::option::OptionStep;
 use crate::process::traversal::step::or::OrStep;
 use crate::process::traversal::step::repeat::RepeatStep;
+use crate::process::traversal::step::sack::SackStep;
 use crate::process::traversal::step::select::SelectStep;
+use crate::process::traversal::step::side_effect::SideEffectStep;
+use crate::process::traversal::step::substring::SubstringStep;
 use crate::process::traversal::step::to::ToStep;
 use crate::process::traversal::step::until::UntilStep;
 use crate::process::traversal::step::where_step::WhereStep;
+use crate::process::traversal::step::with::WithStep;
 
-use crate::process::traversal::{Bytecode, Scope};
-use crate::structure::{Cardinality, GIDs, IntoPredicate, Labels};
+use crate::process::traversal::{Barrier, Bytecode, Operator, Scope, DT};
+use crate::structure::{Cardinality, Direction, GIDs, IntoPredicate, Labels};
 use crate::GValue;
 
 #[derive(Clone)]
@@ -223,6 +234,25 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn to_v(mut self, direction: Direction) -> Self {
+        self.bytecode
+            .add_step(String::from("toV"), vec![direction.into()]);
+
+        self
+    }
+
+    pub fn to_e<A>(mut self, direction: Direction, labels: A) -> Self
+    where
+        A: Into<Labels>,
+    {
+        let mut params = vec![direction.into()];
+        params.extend(labels.into().0.into_iter().map(GValue::from));
+
+        self.bytecode.add_step(String::from("toE"), params);
+
+        self
+    }
+
     pub fn both<A>(mut self, labels: A) -> Self
     where
         A: Into<Labels>,
@@ -376,6 +406,30 @@ impl TraversalBuilder {
         self.bytecode.add_step(String::from("fold"), vec![]);
         self
     }
+
+    pub fn with_<A>(mut self, option: A) -> Self
+    where
+        A: Into<WithStep>,
+    {
+        self.bytecode
+            .add_step(String::from("with"), option.into().into());
+        self
+    }
+
+    pub fn profile(mut self) -> Self {
+        self.bytecode.add_step(String::from("profile"), vec![]);
+        self
+    }
+
+    pub fn fold_with<A>(mut self, seed: A, operator: Operator) -> Self
+    where
+        A: Into<GValue>,
+    {
+        self.bytecode
+            .add_step(String::from("fold"), vec![seed.into(), operator.into()]);
+        self
+    }
+
     pub fn unfold(mut self) -> Self {
         self.bytecode.add_step(String::from("unfold"), vec![]);
         self
@@ -386,6 +440,11 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn tree(mut self) -> Self {
+        self.bytecode.add_step(String::from("tree"), vec![]);
+        self
+    }
+
     pub fn limit<A>(mut self, limit: A) -> Self
     where
         A: Into<LimitStep>,
@@ -608,6 +667,36 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn merge_v<A>(mut self, criteria: A) -> Self
+    where
+        A: Into<MergeStep>,
+    {
+        self.bytecode
+            .add_step(String::from("mergeV"), criteria.into().into());
+
+        self
+    }
+
+    pub fn merge_e<A>(mut self, criteria: A) -> Self
+    where
+        A: Into<MergeStep>,
+    {
+        self.bytecode
+            .add_step(String::from("mergeE"), criteria.into().into());
+
+        self
+    }
+
+    pub fn option<A>(mut self, option: A) -> Self
+    where
+        A: Into<OptionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("option"), option.into().into());
+
+        self
+    }
+
     pub fn identity(mut self) -> Self {
         self.bytecode.add_step(String::from("identity"), vec![]);
         self
@@ -625,11 +714,23 @@ impl TraversalBuilder {
         self
     }
 
+    pub fn subgraph(mut self, step: &'static str) -> Self {
+        self.bytecode
+            .add_step(String::from("subgraph"), vec![step.into()]);
+        self
+    }
+
     pub fn barrier(mut self) -> Self {
         self.bytecode.add_step(String::from("barrier"), vec![]);
         self
     }
 
+    pub fn barrier_with(mut self, barrier: Barrier) -> Self {
+        self.bytecode
+            .add_step(String::from("barrier"), vec![barrier.into()]);
+        self
+    }
+
     pub fn optional(mut self, step: TraversalBuilder) -> Self {
         self.bytecode
             .add_step(String::from("optional"), vec![step.bytecode.into()]);
@@ -649,4 +750,253 @@ impl TraversalBuilder {
         self.bytecode.add_step(String::from("emit"), vec![]);
         self
     }
+
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.bytecode
+            .add_step(String::from("filter"), step.into().into());
+        self
+    }
+
+    pub fn flat_map<A>(mut self, step: A) -> Self
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.bytecode
+            .add_step(String::from("flatMap"), step.into().into());
+        self
+    }
+
+    pub fn side_effect<A>(mut self, step: A) -> Self
+    where
+        A: Into<SideEffectStep>,
+    {
+        self.bytecode
+            .add_step(String::from("sideEffect"), step.into().into());
+        self
+    }
+
+    pub fn sack(mut self) -> Self {
+        self.bytecode.add_step(String::from("sack"), vec![]);
+        self
+    }
+
+    pub fn sack_with<A>(mut self, step: A) -> Self
+    where
+        A: Into<SackStep>,
+    {
+        self.bytecode
+            .add_step(String::from("sack"), step.into().into());
+        self
+    }
+
+    pub fn concat<A>(mut self, step: A) -> Self
+    where
+        A: Into<ConcatStep>,
+    {
+        self.bytecode
+            .add_step(String::from("concat"), step.into().into());
+        self
+    }
+
+    pub fn as_string<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("asString"), scoped(scope.into()));
+        self
+    }
+
+    pub fn to_upper<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("toUpper"), scoped(scope.into()));
+        self
+    }
+
+    pub fn to_lower<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("toLower"), scoped(scope.into()));
+        self
+    }
+
+    pub fn trim<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("trim"), scoped(scope.into()));
+        self
+    }
+
+    pub fn l_trim<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("lTrim"), scoped(scope.into()));
+        self
+    }
+
+    pub fn r_trim<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("rTrim"), scoped(scope.into()));
+        self
+    }
+
+    pub fn replace<A, B>(mut self, old: A, new: B) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        self.bytecode.add_step(
+            String::from("replace"),
+            vec![old.into().into(), new.into().into()],
+        );
+        self
+    }
+
+    pub fn split<A>(mut self, separator: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("split"), vec![separator.into().into()]);
+        self
+    }
+
+    pub fn substring<A>(mut self, step: A) -> Self
+    where
+        A: Into<SubstringStep>,
+    {
+        self.bytecode
+            .add_step(String::from("substring"), step.into().into());
+        self
+    }
+
+    pub fn format<A>(mut self, format: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("format"), vec![format.into().into()]);
+        self
+    }
+
+    pub fn as_date(mut self) -> Self {
+        self.bytecode.add_step(String::from("asDate"), vec![]);
+        self
+    }
+
+    pub fn date_add(mut self, unit: DT, value: i32) -> Self {
+        self.bytecode
+            .add_step(String::from("dateAdd"), vec![unit.into(), value.into()]);
+        self
+    }
+
+    pub fn date_diff<A>(mut self, step: A) -> Self
+    where
+        A: Into<DateDiffStep>,
+    {
+        self.bytecode
+            .add_step(String::from("dateDiff"), step.into().into());
+        self
+    }
+
+    pub fn length<A>(mut self, scope: A) -> Self
+    where
+        A: Into<Scope>,
+    {
+        self.bytecode
+            .add_step(String::from("length"), scoped(scope.into()));
+        self
+    }
+
+    pub fn reverse(mut self) -> Self {
+        self.bytecode.add_step(String::from("reverse"), vec![]);
+        self
+    }
+
+    pub fn combine<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("combine"), step.into().into());
+        self
+    }
+
+    pub fn difference<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("difference"), step.into().into());
+        self
+    }
+
+    pub fn disjunct<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("disjunct"), step.into().into());
+        self
+    }
+
+    pub fn intersect<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("intersect"), step.into().into());
+        self
+    }
+
+    pub fn merge<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("merge"), step.into().into());
+        self
+    }
+
+    pub fn product<A>(mut self, step: A) -> Self
+    where
+        A: Into<CollectionStep>,
+    {
+        self.bytecode
+            .add_step(String::from("product"), step.into().into());
+        self
+    }
+
+    pub fn conjoin<A>(mut self, delimiter: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.bytecode
+            .add_step(String::from("conjoin"), vec![delimiter.into().into()]);
+        self
+    }
+}
+
+// The scoped string steps only exist since TinkerPop 3.7.1, so the global
+// variant is sent without arguments to stay compatible with 3.7.0 servers.
+fn scoped(scope: Scope) -> Vec<GValue> {
+    match scope {
+        Scope::Global => vec![],
+        Scope::Local => vec![scope.into()],
+    }
 }
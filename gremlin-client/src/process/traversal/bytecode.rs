@@ -1,4 +1,5 @@
 use crate::GValue;
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Bytecode {
@@ -31,11 +32,18 @@ impl Bytecode {
     pub fn steps(&self) -> &Vec<Instruction> {
         &self.step_instructions
     }
+
+    pub fn sources(&self) -> &Vec<Instruction> {
+        &self.source_instructions
+    }
 }
 
 lazy_static! {
-    pub static ref WRITE_OPERATORS: Vec<&'static str> =
-        vec!["addV", "property", "addE", "from", "to", "drop"];
+    pub static ref WRITE_OPERATORS: Vec<&'static str> = vec![
+        "addV", "property", "addE", "from", "to", "drop", "mergeV", "mergeE",
+        // io().read() imports the file into the graph
+        "read",
+    ];
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -57,3 +65,38 @@ impl Instruction {
         &self.args
     }
 }
+
+/// Written as `[[sources], [steps]]`, e.g. `[[], [V(), out(knows)]]`
+impl fmt::Display for Bytecode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        write_instructions(f, &self.source_instructions)?;
+        write!(f, ", ")?;
+        write_instructions(f, &self.step_instructions)?;
+        write!(f, "]")
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}(", self.operator)?;
+        for (i, arg) in self.args.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", arg)?;
+        }
+        write!(f, ")")
+    }
+}
+
+fn write_instructions(f: &mut fmt::Formatter, instructions: &[Instruction]) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, instruction) in instructions.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", instruction)?;
+    }
+    write!(f, "]")
+}
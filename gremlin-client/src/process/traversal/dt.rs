@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DT {
+    Second,
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Display for DT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DT::Second => write!(f, "second"),
+            DT::Minute => write!(f, "minute"),
+            DT::Hour => write!(f, "hour"),
+            DT::Day => write!(f, "day"),
+        }
+    }
+}
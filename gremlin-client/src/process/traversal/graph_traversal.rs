@@ -2,33 +2,51 @@ use crate::conversion::FromGValue;
 use crate::process::traversal::step::by::ByStep;
 use crate::process::traversal::step::choose::IntoChooseStep;
 use crate::process::traversal::step::coalesce::CoalesceStep;
+use crate::process::traversal::step::collection::CollectionStep;
+use crate::process::traversal::step::concat::ConcatStep;
+use crate::process::traversal::step::date_diff::DateDiffStep;
 use crate::process::traversal::step::dedup::DedupStep;
+use crate::process::traversal::step::filter::FilterStep;
+use crate::process::traversal::step::flat_map::FlatMapStep;
 use crate::process::traversal::step::from::FromStep;
 use crate::process::traversal::step::has::HasStep;
 use crate::process::traversal::step::limit::LimitStep;
 use crate::process::traversal::step::local::LocalStep;
 use crate::process::traversal::step::loops::LoopsStep;
 use crate::process::traversal::step::match_step::MatchStep;
+use crate::process::traversal::step::merge_step::MergeStep;
 use crate::process::traversal::step::not::NotStep;
+use crate::process::traversal::step::option::OptionStep;
 use crate::process::traversal::step::or::OrStep;
 use crate::process::traversal::step::repeat::RepeatStep;
+use crate::process::traversal::step::sack::SackStep;
 use crate::process::traversal::step::select::SelectStep;
+use crate::process::traversal::step::side_effect::SideEffectStep;
+use crate::process::traversal::step::substring::SubstringStep;
 use crate::process::traversal::step::to::ToStep;
 use crate::process::traversal::step::until::UntilStep;
 use crate::process::traversal::step::where_step::WhereStep;
+use crate::process::traversal::step::with::WithStep;
 
 use crate::process::traversal::remote::{SyncTerminator, Terminator};
-use crate::process::traversal::strategies::{
-    RemoteStrategy, TraversalStrategies, TraversalStrategy,
+use crate::process::traversal::strategies::{RemoteStrategy, TraversalStrategies};
+use crate::process::traversal::{
+    Barrier, Bytecode, Operator, Scope, TraversalBuilder, DT, WRITE_OPERATORS,
+};
+use crate::structure::{
+    Cardinality, Direction, Labels, TraversalExplanation, TraversalMetrics, Tree,
 };
-use crate::process::traversal::{Bytecode, Scope, TraversalBuilder, WRITE_OPERATORS};
-use crate::structure::{Cardinality, Labels};
 use crate::{
     structure::GIDs, structure::GProperty, structure::IntoPredicate, Edge, GValue, GremlinClient,
-    List, Map, Path, Vertex,
+    GremlinResult, List, Map, Path, Vertex,
 };
 use std::marker::PhantomData;
 
+#[cfg(feature = "async_gremlin")]
+use crate::aio::process::traversal::remote::AsyncTerminator;
+#[cfg(feature = "async_gremlin")]
+use futures::future::BoxFuture;
+
 #[derive(Clone)]
 pub struct GraphTraversal<S, E: FromGValue, T: Terminator<E>> {
     start: PhantomData<S>,
@@ -48,18 +66,27 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
     }
 
     pub fn change_remote(self, client: GremlinClient) -> GraphTraversal<S, E, SyncTerminator> {
-        let mut strategies = TraversalStrategies::new(vec![]);
-
-        strategies.add_strategy(TraversalStrategy::Remote(RemoteStrategy::new(client)));
-
         GraphTraversal {
             start: self.start,
             end: self.end,
             builder: self.builder,
-            terminator: SyncTerminator::new(strategies),
+            terminator: SyncTerminator::new(
+                TraversalStrategies::new(),
+                RemoteStrategy::new(client),
+            ),
         }
     }
 
+    /// Rewrite the steps of this traversal with a function over its
+    /// [TraversalBuilder], e.g. to share custom steps with anonymous traversals.
+    pub fn with_builder<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(TraversalBuilder) -> TraversalBuilder,
+    {
+        self.builder = f(self.builder);
+        self
+    }
+
     pub fn does_write(&self) -> bool {
         self.bytecode()
             .steps()
@@ -242,6 +269,25 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         GraphTraversal::new(self.terminator, self.builder)
     }
 
+    pub fn to_v(mut self, direction: Direction) -> GraphTraversal<S, Vertex, T>
+    where
+        T: Terminator<Vertex>,
+    {
+        self.builder = self.builder.to_v(direction);
+
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn to_e<A>(mut self, direction: Direction, labels: A) -> GraphTraversal<S, Edge, T>
+    where
+        A: Into<Labels>,
+        T: Terminator<Edge>,
+    {
+        self.builder = self.builder.to_e(direction, labels);
+
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn both<A>(mut self, labels: A) -> GraphTraversal<S, Vertex, T>
     where
         A: Into<Labels>,
@@ -304,6 +350,22 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self.terminator.iter(self)
     }
 
+    pub fn with_<A>(mut self, option: A) -> Self
+    where
+        A: Into<WithStep>,
+    {
+        self.builder = self.builder.with_(option);
+        self
+    }
+
+    pub fn profile(mut self) -> GraphTraversal<S, TraversalMetrics, T>
+    where
+        T: Terminator<TraversalMetrics>,
+    {
+        self.builder = self.builder.profile();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn from<A>(mut self, target: A) -> Self
     where
         A: Into<FromStep>,
@@ -424,6 +486,16 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self.builder = self.builder.fold();
         GraphTraversal::new(self.terminator, self.builder)
     }
+
+    pub fn fold_with<A>(mut self, seed: A, operator: Operator) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<GValue>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.fold_with(seed, operator);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn unfold(mut self) -> Self {
         self.builder = self.builder.unfold();
         self
@@ -437,6 +509,14 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         GraphTraversal::new(self.terminator, self.builder)
     }
 
+    pub fn tree(mut self) -> GraphTraversal<S, Tree, T>
+    where
+        T: Terminator<Tree>,
+    {
+        self.builder = self.builder.tree();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
     pub fn limit<A>(mut self, limit: A) -> Self
     where
         A: Into<LimitStep>,
@@ -651,6 +731,36 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         GraphTraversal::new(self.terminator, self.builder)
     }
 
+    /// `T` and `Direction` keys in the criteria map can only be written with GraphSON V3.
+    pub fn merge_v<A>(mut self, criteria: A) -> GraphTraversal<S, Vertex, T>
+    where
+        A: Into<MergeStep>,
+        T: Terminator<Vertex>,
+    {
+        self.builder = self.builder.merge_v(criteria);
+
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    /// `T` and `Direction` keys in the criteria map can only be written with GraphSON V3.
+    pub fn merge_e<A>(mut self, criteria: A) -> GraphTraversal<S, Edge, T>
+    where
+        A: Into<MergeStep>,
+        T: Terminator<Edge>,
+    {
+        self.builder = self.builder.merge_e(criteria);
+
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn option<A>(mut self, option: A) -> Self
+    where
+        A: Into<OptionStep>,
+    {
+        self.builder = self.builder.option(option);
+        self
+    }
+
     pub fn identity(mut self) -> Self {
         self.builder = self.builder.identity();
         self
@@ -666,11 +776,21 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self
     }
 
+    pub fn subgraph(mut self, step: &'static str) -> Self {
+        self.builder = self.builder.subgraph(step);
+        self
+    }
+
     pub fn barrier(mut self) -> Self {
         self.builder = self.builder.barrier();
         self
     }
 
+    pub fn barrier_with(mut self, barrier: Barrier) -> Self {
+        self.builder = self.builder.barrier_with(barrier);
+        self
+    }
+
     pub fn optional(mut self, step: TraversalBuilder) -> Self {
         self.builder = self.builder.optional(step);
         self
@@ -688,4 +808,275 @@ impl<S, E: FromGValue, T: Terminator<E>> GraphTraversal<S, E, T> {
         self.builder = self.builder.emit();
         self
     }
+
+    pub fn filter<A>(mut self, step: A) -> Self
+    where
+        A: Into<FilterStep>,
+    {
+        self.builder = self.builder.filter(step);
+        self
+    }
+
+    pub fn flat_map<A>(mut self, step: A) -> Self
+    where
+        A: Into<FlatMapStep>,
+    {
+        self.builder = self.builder.flat_map(step);
+        self
+    }
+
+    pub fn side_effect<A>(mut self, step: A) -> Self
+    where
+        A: Into<SideEffectStep>,
+    {
+        self.builder = self.builder.side_effect(step);
+        self
+    }
+
+    pub fn sack(mut self) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.sack();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn sack_with<A>(mut self, step: A) -> Self
+    where
+        A: Into<SackStep>,
+    {
+        self.builder = self.builder.sack_with(step);
+        self
+    }
+
+    pub fn concat<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<ConcatStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.concat(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn as_string<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.as_string(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn to_upper<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.to_upper(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn to_lower<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.to_lower(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn trim<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.trim(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn l_trim<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.l_trim(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn r_trim<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.r_trim(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn replace<A, B>(mut self, old: A, new: B) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<String>,
+        B: Into<String>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.replace(old, new);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn split<A>(mut self, separator: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<String>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.split(separator);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn substring<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<SubstringStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.substring(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn format<A>(mut self, format: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<String>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.format(format);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn as_date(mut self) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.as_date();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn date_add(mut self, unit: DT, value: i32) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.date_add(unit, value);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn date_diff<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<DateDiffStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.date_diff(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn length<A>(mut self, scope: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<Scope>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.length(scope);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn reverse(mut self) -> GraphTraversal<S, GValue, T>
+    where
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.reverse();
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn combine<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.combine(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn difference<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.difference(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn disjunct<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.disjunct(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn intersect<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.intersect(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn merge<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.merge(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn product<A>(mut self, step: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<CollectionStep>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.product(step);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+
+    pub fn conjoin<A>(mut self, delimiter: A) -> GraphTraversal<S, GValue, T>
+    where
+        A: Into<String>,
+        T: Terminator<GValue>,
+    {
+        self.builder = self.builder.conjoin(delimiter);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+}
+
+impl<S, E: FromGValue> GraphTraversal<S, E, SyncTerminator> {
+    /// Explain how the server strategies rewrite this traversal, without executing it.
+    ///
+    /// Bytecode has no `explain` step, so the traversal is explained through a Groovy script:
+    /// this fails on servers without a script engine, e.g. Amazon Neptune or servers that
+    /// only accept bytecode.
+    pub fn explain(&self) -> GremlinResult<TraversalExplanation> {
+        self.terminator.explain(self.bytecode())
+    }
+}
+
+#[cfg(feature = "async_gremlin")]
+impl<S, E> GraphTraversal<S, E, AsyncTerminator>
+where
+    E: FromGValue + Send + 'static,
+{
+    /// Explain how the server strategies rewrite this traversal, without executing it.
+    ///
+    /// Bytecode has no `explain` step, so the traversal is explained through a Groovy script:
+    /// this fails on servers without a script engine, e.g. Amazon Neptune or servers that
+    /// only accept bytecode.
+    pub fn explain(&self) -> BoxFuture<'static, GremlinResult<TraversalExplanation>> {
+        self.terminator.explain(self.bytecode())
+    }
 }
@@ -7,9 +7,12 @@ use crate::process::traversal::strategies::{
 use crate::aio::process::traversal::remote::AsyncTerminator;
 #[cfg(feature = "async_gremlin")]
 use crate::aio::GremlinClient as GremlinAsyncClient;
+#[cfg(feature = "embedded")]
+use crate::embedded::{EmbeddedTerminator, TinkerGraph};
 use crate::process::traversal::remote::{MockTerminator, SyncTerminator, Terminator};
+use crate::process::traversal::step::merge_step::MergeStep;
 use crate::process::traversal::Bytecode;
-use crate::process::traversal::{GraphTraversal, TraversalBuilder};
+use crate::process::traversal::{GraphTraversal, IoTraversal, TraversalBuilder};
 use crate::structure::GIDs;
 use crate::structure::Labels;
 use crate::structure::{Edge, GValue, Vertex};
@@ -30,12 +33,8 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
     }
 
     pub fn with_remote(&self, client: GremlinClient) -> GraphTraversalSource<SyncTerminator> {
-        let mut strategies = TraversalStrategies::new(vec![]);
-
-        strategies.add_strategy(TraversalStrategy::Remote(RemoteStrategy::new(client)));
-
         GraphTraversalSource {
-            term: SyncTerminator::new(strategies),
+            term: SyncTerminator::new(TraversalStrategies::new(), RemoteStrategy::new(client)),
         }
     }
 
@@ -49,6 +48,23 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         }
     }
 
+    #[cfg(feature = "embedded")]
+    pub fn with_embedded(&self, graph: TinkerGraph) -> GraphTraversalSource<EmbeddedTerminator> {
+        GraphTraversalSource {
+            term: EmbeddedTerminator::new(graph),
+        }
+    }
+
+    /// Rebuild a traversal from previously serialized bytecode, e.g. read back with
+    /// [GraphSON::read](crate::GraphSON::read)
+    pub fn from_bytecode<E>(&self, bytecode: Bytecode) -> GraphTraversal<GValue, E, A>
+    where
+        E: FromGValue,
+        A: Terminator<E>,
+    {
+        GraphTraversal::new(self.term.clone(), TraversalBuilder::new(bytecode))
+    }
+
     pub fn v<T>(&self, ids: T) -> GraphTraversal<Vertex, Vertex, A>
     where
         T: Into<GIDs>,
@@ -91,6 +107,43 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
 
+    /// `T` and `Direction` keys in the criteria map can only be written with GraphSON V3.
+    pub fn merge_v<T>(&self, criteria: T) -> GraphTraversal<Vertex, Vertex, A>
+    where
+        T: Into<MergeStep>,
+        A: Terminator<Vertex>,
+    {
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("mergeV"), criteria.into().into());
+
+        GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
+    }
+
+    /// `T` and `Direction` keys in the criteria map can only be written with GraphSON V3.
+    pub fn merge_e<T>(&self, criteria: T) -> GraphTraversal<Edge, Edge, A>
+    where
+        T: Into<MergeStep>,
+        A: Terminator<Edge>,
+    {
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("mergeE"), criteria.into().into());
+
+        GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
+    }
+
+    pub fn io<T>(&self, file: T) -> IoTraversal<A>
+    where
+        T: Into<String>,
+    {
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("io"), vec![file.into().into()]);
+
+        IoTraversal::new(self.term.clone(), TraversalBuilder::new(code))
+    }
+
     pub fn e<T>(&self, ids: T) -> GraphTraversal<Edge, Edge, A>
     where
         T: Into<GIDs>,
@@ -119,6 +172,44 @@ impl<A: Terminator<GValue>> GraphTraversalSource<A> {
         );
         GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
     }
+
+    pub fn with_sack<T>(&self, initial: T) -> GraphTraversal<GValue, GValue, A>
+    where
+        T: Into<GValue>,
+        A: Terminator<GValue>,
+    {
+        let mut code = Bytecode::new();
+
+        code.add_source(String::from("withSack"), vec![initial.into()]);
+        GraphTraversal::new(self.term.clone(), TraversalBuilder::new(code))
+    }
+}
+
+impl GraphTraversalSource<SyncTerminator> {
+    /// Register a [TraversalStrategy](trait.TraversalStrategy) applied to every traversal
+    /// spawned from this source
+    pub fn with_strategy<S>(&self, strategy: S) -> GraphTraversalSource<SyncTerminator>
+    where
+        S: TraversalStrategy + 'static,
+    {
+        GraphTraversalSource {
+            term: self.term.clone().with_strategy(strategy),
+        }
+    }
+}
+
+#[cfg(feature = "async_gremlin")]
+impl GraphTraversalSource<AsyncTerminator> {
+    /// Register a [TraversalStrategy](trait.TraversalStrategy) applied to every traversal
+    /// spawned from this source
+    pub fn with_strategy<S>(&self, strategy: S) -> GraphTraversalSource<AsyncTerminator>
+    where
+        S: TraversalStrategy + 'static,
+    {
+        GraphTraversalSource {
+            term: self.term.clone().with_strategy(strategy),
+        }
+    }
 }
 
 // TESTS
@@ -128,8 +219,11 @@ mod tests {
     use crate::process::traversal::remote::MockTerminator;
 
     use super::GraphTraversalSource;
-    use crate::process::traversal::{Bytecode, Order, Scope, __};
-    use crate::structure::{GValue, P, T};
+    use crate::process::traversal::{
+        Barrier, Bytecode, Merge, Operator, Order, Pick, Scope, __, DT, IO,
+    };
+    use crate::structure::{Binding, Column, Direction, GKey, GValue, Lambda, P, T};
+    use std::collections::HashMap;
 
     fn empty() -> GraphTraversalSource<MockTerminator> {
         GraphTraversalSource::new(MockTerminator {})
@@ -943,4 +1037,378 @@ mod tests {
     }
 
     // g.V().hasLabel('person').coalesce(values('nickname'), values('name'))
+
+    #[test]
+    fn lambda_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_source(String::from("withSack"), vec![1.into()]);
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("filter"),
+            vec![Lambda::new("it.get().label() == 'person'").into()],
+        );
+        code.add_step(
+            String::from("sack"),
+            vec![Lambda::new("a, b -> a + b").with_arguments(2).into()],
+        );
+        code.add_step(
+            String::from("map"),
+            vec![Lambda::new("it.get().value('name')").into()],
+        );
+        code.add_step(String::from("order"), vec![Scope::Global.into()]);
+        code.add_step(
+            String::from("by"),
+            vec![Lambda::new("a, b -> a <=> b").with_arguments(2).into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.with_sack(1)
+                .v(())
+                .filter(Lambda::new("it.get().label() == 'person'"))
+                .sack_with(Lambda::new("a, b -> a + b").with_arguments(2))
+                .map(Lambda::new("it.get().value('name')"))
+                .order(())
+                .by(Lambda::new("a, b -> a <=> b").with_arguments(2))
+                .bytecode()
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_source(String::from("withSack"), vec![1.into()]);
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("sack"), vec![]);
+
+        assert_eq!(&code, g.with_sack(1).v(()).sack().bytecode());
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("flatMap"),
+            vec![__.out(()).bytecode().clone().into()],
+        );
+        code.add_step(
+            String::from("sideEffect"),
+            vec![Lambda::new("println it").into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .flat_map(__.out(()))
+                .side_effect(Lambda::new("println it"))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn from_bytecode_test() {
+        let g = empty();
+
+        let code = g
+            .with_side_effect(("a", 1))
+            .v(())
+            .has_label("person")
+            .bytecode()
+            .clone();
+
+        assert_eq!(&code, g.from_bytecode::<GValue>(code.clone()).bytecode());
+    }
+
+    #[test]
+    fn io_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("io"), vec!["air-routes.xml".into()]);
+        code.add_step(
+            String::from("with"),
+            vec![IO::READER.into(), IO::GRAPHML.into()],
+        );
+        code.add_step(String::from("read"), vec![]);
+
+        assert_eq!(
+            &code,
+            g.io("air-routes.xml")
+                .with_((IO::READER, IO::GRAPHML))
+                .read()
+                .bytecode()
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("io"), vec!["export.json".into()]);
+        code.add_step(
+            String::from("with"),
+            vec![IO::WRITER.into(), IO::GRAPHSON.into()],
+        );
+        code.add_step(String::from("write"), vec![]);
+
+        assert_eq!(
+            &code,
+            g.io("export.json")
+                .with_((IO::WRITER, IO::GRAPHSON))
+                .write()
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn profile_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("out"), vec![]);
+        code.add_step(String::from("profile"), vec![]);
+
+        assert_eq!(&code, g.v(()).out(()).profile().bytecode());
+    }
+
+    #[test]
+    fn tree_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("out"), vec![]);
+        code.add_step(String::from("tree"), vec![]);
+        code.add_step(String::from("by"), vec!["name".into()]);
+
+        assert_eq!(&code, g.v(()).out(()).tree().by("name").bytecode());
+    }
+
+    #[test]
+    fn subgraph_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("E"), vec![]);
+        code.add_step(String::from("subgraph"), vec!["sg".into()]);
+        code.add_step(String::from("cap"), vec!["sg".into()]);
+
+        assert_eq!(&code, g.e(()).subgraph("sg").cap("sg").bytecode());
+    }
+
+    #[test]
+    fn merge_v_test() {
+        let g = empty();
+
+        let mut criteria: HashMap<GKey, GValue> = HashMap::new();
+        criteria.insert(T::Label.into(), "person".into());
+        criteria.insert("name".into(), "marko".into());
+
+        let mut on_create: HashMap<GKey, GValue> = HashMap::new();
+        on_create.insert("created".into(), true.into());
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("mergeV"), vec![criteria.clone().into()]);
+        code.add_step(
+            String::from("option"),
+            vec![Merge::OnCreate.into(), on_create.clone().into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.merge_v(criteria)
+                .option((Merge::OnCreate, on_create))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn merge_e_test() {
+        let g = empty();
+
+        let mut criteria: HashMap<GKey, GValue> = HashMap::new();
+        criteria.insert(T::Label.into(), "knows".into());
+        criteria.insert(Direction::Out.into(), 1.into());
+        criteria.insert(Direction::In.into(), 2.into());
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("mergeE"), vec![criteria.clone().into()]);
+        code.add_step(
+            String::from("option"),
+            vec![
+                Merge::OnMatch.into(),
+                __.constant(GValue::from(criteria.clone()))
+                    .bytecode()
+                    .clone()
+                    .into(),
+            ],
+        );
+
+        assert_eq!(
+            &code,
+            g.merge_e(criteria.clone())
+                .option((Merge::OnMatch, __.constant(GValue::from(criteria))))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn string_steps_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("values"), vec!["name".into()]);
+        code.add_step(String::from("toUpper"), vec![]);
+        code.add_step(String::from("trim"), vec![Scope::Local.into()]);
+        code.add_step(String::from("replace"), vec!["A".into(), "B".into()]);
+        code.add_step(String::from("substring"), vec![1.into(), 3.into()]);
+        code.add_step(
+            String::from("concat"),
+            vec![__.constant("!").bytecode().clone().into()],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .values("name")
+                .to_upper(())
+                .trim(Scope::Local)
+                .replace("A", "B")
+                .substring((1, 3))
+                .concat(__.constant("!"))
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn date_and_list_steps_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("values"), vec!["born".into()]);
+        code.add_step(String::from("asDate"), vec![]);
+        code.add_step(String::from("dateAdd"), vec![DT::Day.into(), 7.into()]);
+        code.add_step(String::from("fold"), vec![]);
+        code.add_step(
+            String::from("intersect"),
+            vec![vec![GValue::from(1), GValue::from(2)].into()],
+        );
+        code.add_step(String::from("conjoin"), vec!["-".into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .values("born")
+                .as_date()
+                .date_add(DT::Day, 7)
+                .fold()
+                .intersect(vec![GValue::from(1), GValue::from(2)])
+                .conjoin("-")
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn column_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(String::from("group"), vec![]);
+        code.add_step(String::from("by"), vec!["name".into()]);
+        code.add_step(
+            String::from("by"),
+            vec![__.count().bytecode().clone().into()],
+        );
+        code.add_step(String::from("unfold"), vec![]);
+        code.add_step(String::from("order"), vec![Scope::Global.into()]);
+        code.add_step(
+            String::from("by"),
+            vec![Column::Values.into(), Order::Desc.into()],
+        );
+        code.add_step(String::from("select"), vec![Column::Keys.into()]);
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .group()
+                .by("name")
+                .by(__.count())
+                .unfold()
+                .order(())
+                .by((Column::Values, Order::Desc))
+                .select(Column::Keys)
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn direction_operator_pick_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("E"), vec![]);
+        code.add_step(String::from("toV"), vec![Direction::In.into()]);
+        code.add_step(
+            String::from("toE"),
+            vec![Direction::Both.into(), "knows".into()],
+        );
+        code.add_step(String::from("sack"), vec![Operator::Sum.into()]);
+        code.add_step(String::from("barrier"), vec![Barrier::NormSack.into()]);
+        code.add_step(
+            String::from("choose"),
+            vec![__.values("age").bytecode().clone().into()],
+        );
+        code.add_step(
+            String::from("option"),
+            vec![Pick::None.into(), __.out(()).bytecode().clone().into()],
+        );
+        code.add_step(String::from("fold"), vec![0.into(), Operator::Sum.into()]);
+
+        assert_eq!(
+            &code,
+            g.e(())
+                .to_v(Direction::In)
+                .to_e(Direction::Both, "knows")
+                .sack_with(Operator::Sum)
+                .barrier_with(Barrier::NormSack)
+                .choose(__.values("age"))
+                .option((Pick::None, __.out(())))
+                .fold_with(0, Operator::Sum)
+                .bytecode()
+        );
+    }
+
+    #[test]
+    fn binding_test() {
+        let g = empty();
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("has"),
+            vec![
+                "code".into(),
+                P::new("eq", Binding::new("code", "AUS").into()).into(),
+            ],
+        );
+
+        assert_eq!(
+            &code,
+            g.v(())
+                .has(("code", Binding::new("code", "AUS")))
+                .bytecode()
+        );
+    }
 }
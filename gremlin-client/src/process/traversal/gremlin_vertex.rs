@@ -0,0 +1,16 @@
+use crate::process::traversal::{GraphTraversal, GraphTraversalSource, Terminator};
+use crate::structure::{GValue, Vertex};
+
+/// Map a struct onto a vertex, usually implemented with `#[derive(GremlinVertex)]`.
+pub trait GremlinVertex {
+    /// `addV(label)` followed by one `property` step per field
+    fn create<A>(&self, g: &GraphTraversalSource<A>) -> GraphTraversal<Vertex, Vertex, A>
+    where
+        A: Terminator<GValue> + Terminator<Vertex>;
+
+    /// `V(id)` when the struct has an id field, `V().hasLabel(label).has(..)` on every
+    /// property otherwise
+    fn lookup<A>(&self, g: &GraphTraversalSource<A>) -> GraphTraversal<Vertex, Vertex, A>
+    where
+        A: Terminator<GValue> + Terminator<Vertex>;
+}
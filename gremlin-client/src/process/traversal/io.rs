@@ -0,0 +1,57 @@
+use crate::process::traversal::step::with::WithStep;
+use crate::process::traversal::{GraphTraversal, Terminator, TraversalBuilder};
+use crate::GValue;
+
+/// Keys and values of the `with` options understood by the `io()` step.
+pub struct IO;
+
+impl IO {
+    pub const READER: &'static str = "~tinkerpop.io.reader";
+    pub const WRITER: &'static str = "~tinkerpop.io.writer";
+    pub const REGISTRY: &'static str = "~tinkerpop.io.registry";
+
+    pub const GRAPHML: &'static str = "graphml";
+    pub const GRAPHSON: &'static str = "graphson";
+    pub const GRYO: &'static str = "gryo";
+}
+
+/// The traversal started by [io](struct.GraphTraversalSource.html#method.io), completed by
+/// [read](#method.read) or [write](#method.write).
+#[derive(Clone)]
+pub struct IoTraversal<T: Terminator<GValue>> {
+    terminator: T,
+    builder: TraversalBuilder,
+}
+
+impl<T: Terminator<GValue>> IoTraversal<T> {
+    pub(crate) fn new(terminator: T, builder: TraversalBuilder) -> IoTraversal<T> {
+        IoTraversal {
+            terminator,
+            builder,
+        }
+    }
+
+    pub fn with_<A>(mut self, option: A) -> Self
+    where
+        A: Into<WithStep>,
+    {
+        self.builder = self.builder.with_(option);
+        self
+    }
+
+    /// Import the file into the graph. The traversal counts as a write for
+    /// [ReadOnlyStrategy](struct.ReadOnlyStrategy.html).
+    pub fn read(self) -> GraphTraversal<GValue, GValue, T> {
+        self.finish("read")
+    }
+
+    /// Export the graph to the file
+    pub fn write(self) -> GraphTraversal<GValue, GValue, T> {
+        self.finish("write")
+    }
+
+    fn finish(mut self, step: &str) -> GraphTraversal<GValue, GValue, T> {
+        self.builder.bytecode.add_step(String::from(step), vec![]);
+        GraphTraversal::new(self.terminator, self.builder)
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Merge {
+    OnCreate,
+    OnMatch,
+    OutV,
+    InV,
+}
+
+impl Display for Merge {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Merge::OnCreate => write!(f, "onCreate"),
+            Merge::OnMatch => write!(f, "onMatch"),
+            Merge::OutV => write!(f, "outV"),
+            Merge::InV => write!(f, "inV"),
+        }
+    }
+}
@@ -5,23 +5,41 @@ use crate::GremlinResult;
 use std::marker::PhantomData;
 
 mod anonymous_traversal_source;
+mod barrier;
 mod builder;
 mod bytecode;
+mod dt;
 mod graph_traversal;
 mod graph_traversal_source;
+mod gremlin_vertex;
+mod io;
+mod merge;
+mod operator;
 mod order;
+mod pick;
 pub(crate) mod remote;
 mod scope;
 mod step;
-mod strategies;
+pub(crate) mod strategies;
+pub use barrier::Barrier;
+pub use dt::DT;
+pub use io::{IoTraversal, IO};
+pub use merge::Merge;
+pub use operator::Operator;
 pub use order::Order;
+pub use pick::Pick;
 pub use remote::{traversal, SyncTerminator, Terminator};
 
 pub use builder::TraversalBuilder;
-pub use bytecode::{Bytecode, WRITE_OPERATORS};
+pub use bytecode::{Bytecode, Instruction, WRITE_OPERATORS};
 pub use graph_traversal::GraphTraversal;
 pub use graph_traversal_source::GraphTraversalSource;
+pub use gremlin_vertex::GremlinVertex;
 pub use scope::Scope;
+pub use strategies::{
+    ReadOnlyStrategy, RemoteStrategy, StrategyCategory, TenantStrategy, TraversalStrategies,
+    TraversalStrategy,
+};
 
 pub use anonymous_traversal_source::AnonymousTraversalSource;
 
@@ -36,6 +54,7 @@ pub trait Traversal<S, E> {
 pub struct RemoteTraversalIterator<T: FromGValue> {
     data: PhantomData<T>,
     result: GResultSet,
+    current: Option<Traverser>,
 }
 
 impl<T: FromGValue> RemoteTraversalIterator<T> {
@@ -43,6 +62,7 @@ impl<T: FromGValue> RemoteTraversalIterator<T> {
         RemoteTraversalIterator {
             result,
             data: PhantomData,
+            current: None,
         }
     }
 }
@@ -50,12 +70,19 @@ impl<T: FromGValue> RemoteTraversalIterator<T> {
 impl<T: FromGValue> Iterator for RemoteTraversalIterator<T> {
     type Item = GremlinResult<T>;
 
-    // todo remove unwrap
     fn next(&mut self) -> Option<Self::Item> {
-        self.result
-            .next()
-            .map(|e| e.unwrap().take::<Traverser>())
-            .map(|t| t.unwrap().take::<T>())
+        loop {
+            if let Some(traverser) = self.current.take() {
+                if let Some((value, rest)) = traverser.split() {
+                    self.current = rest;
+                    return Some(value.take::<T>());
+                }
+            }
+            match self.result.next()?.and_then(|e| e.take::<Traverser>()) {
+                Ok(traverser) => self.current = Some(traverser),
+                Err(e) => return Some(Err(e)),
+            }
+        }
     }
 }
 
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Operator {
+    Sum,
+    Minus,
+    Mult,
+    Div,
+    Min,
+    Max,
+    Assign,
+    And,
+    Or,
+    AddAll,
+    SumLong,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Operator::Sum => write!(f, "sum"),
+            Operator::Minus => write!(f, "minus"),
+            Operator::Mult => write!(f, "mult"),
+            Operator::Div => write!(f, "div"),
+            Operator::Min => write!(f, "min"),
+            Operator::Max => write!(f, "max"),
+            Operator::Assign => write!(f, "assign"),
+            Operator::And => write!(f, "and"),
+            Operator::Or => write!(f, "or"),
+            Operator::AddAll => write!(f, "addAll"),
+            Operator::SumLong => write!(f, "sumLong"),
+        }
+    }
+}
@@ -1,6 +1,18 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Order {
     Asc,
     Desc,
     Shuffle,
 }
+
+impl Display for Order {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Order::Asc => write!(f, "asc"),
+            Order::Desc => write!(f, "desc"),
+            Order::Shuffle => write!(f, "shuffle"),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Pick {
+    Any,
+    None,
+}
+
+impl Display for Pick {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Pick::Any => write!(f, "any"),
+            Pick::None => write!(f, "none"),
+        }
+    }
+}
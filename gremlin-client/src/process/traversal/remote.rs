@@ -1,8 +1,11 @@
 use crate::client::GremlinClient;
 use crate::conversion::FromGValue;
-use crate::process::traversal::strategies::TraversalStrategies;
+use crate::process::traversal::strategies::{
+    RemoteStrategy, TraversalStrategies, TraversalStrategy,
+};
 use crate::process::traversal::RemoteTraversalIterator;
-use crate::process::traversal::{GraphTraversal, GraphTraversalSource};
+use crate::process::traversal::{Bytecode, GraphTraversal, GraphTraversalSource};
+use crate::structure::TraversalExplanation;
 use crate::GremlinResult;
 
 #[cfg(feature = "async_gremlin")]
@@ -11,6 +14,9 @@ use crate::aio::GremlinClient as GremlinAsyncClient;
 #[cfg(feature = "async_gremlin")]
 use crate::aio::process::traversal::remote::AsyncTerminator;
 
+#[cfg(feature = "embedded")]
+use crate::embedded::{EmbeddedTerminator, TinkerGraph};
+
 pub struct RemoteTraversalSource {}
 
 impl RemoteTraversalSource {
@@ -29,6 +35,11 @@ impl RemoteTraversalSource {
     ) -> GraphTraversalSource<AsyncTerminator> {
         GraphTraversalSource::<MockTerminator>::new(MockTerminator {}).with_remote_async(client)
     }
+
+    #[cfg(feature = "embedded")]
+    pub fn with_embedded(&self, graph: TinkerGraph) -> GraphTraversalSource<EmbeddedTerminator> {
+        GraphTraversalSource::<MockTerminator>::new(MockTerminator {}).with_embedded(graph)
+    }
 }
 
 pub fn traversal() -> RemoteTraversalSource {
@@ -109,11 +120,33 @@ pub trait Terminator<T: FromGValue>: Clone {
 #[derive(Clone)]
 pub struct SyncTerminator {
     strategies: TraversalStrategies,
+    remote: RemoteStrategy,
 }
 
 impl SyncTerminator {
-    pub fn new(strategies: TraversalStrategies) -> SyncTerminator {
-        SyncTerminator { strategies }
+    /// `strategies` rewrite the bytecode of each traversal, which is then submitted through
+    /// `remote`.
+    pub fn new(strategies: TraversalStrategies, remote: RemoteStrategy) -> SyncTerminator {
+        SyncTerminator { strategies, remote }
+    }
+
+    pub fn with_strategy<S>(mut self, strategy: S) -> SyncTerminator
+    where
+        S: TraversalStrategy + 'static,
+    {
+        self.strategies.add_strategy(strategy);
+        self
+    }
+
+    fn submit<T: FromGValue>(
+        &self,
+        bytecode: &Bytecode,
+    ) -> GremlinResult<RemoteTraversalIterator<T>> {
+        self.remote.apply(&self.strategies.apply(bytecode)?)
+    }
+
+    pub(crate) fn explain(&self, bytecode: &Bytecode) -> GremlinResult<TraversalExplanation> {
+        self.remote.explain(&self.strategies.apply(bytecode)?)
     }
 }
 
@@ -127,31 +160,31 @@ impl<T: FromGValue> Terminator<T> for SyncTerminator {
     where
         E: Terminator<T>,
     {
-        self.strategies.apply(traversal)?.collect()
+        self.submit(traversal.bytecode())?.collect()
     }
 
     fn next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Next
     where
         E: Terminator<T>,
     {
-        let results: GremlinResult<Vec<T>> = self.strategies.apply(traversal)?.collect();
-
-        Ok(results?.into_iter().next())
+        self.submit(traversal.bytecode())?.next().transpose()
     }
 
     fn has_next<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::HasNext
     where
         E: Terminator<T>,
     {
-        let results: GremlinResult<Vec<T>> = self.strategies.apply(traversal)?.collect();
-
-        Ok(results?.iter().next().is_some())
+        Ok(self
+            .submit::<T>(traversal.bytecode())?
+            .next()
+            .transpose()?
+            .is_some())
     }
 
     fn iter<S, E>(&self, traversal: &GraphTraversal<S, T, E>) -> Self::Iter
     where
         E: Terminator<T>,
     {
-        self.strategies.apply(traversal)
+        self.submit(traversal.bytecode())
     }
 }
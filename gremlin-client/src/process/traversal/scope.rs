@@ -1,9 +1,20 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Scope {
     Global,
     Local,
 }
 
+impl Display for Scope {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Scope::Global => write!(f, "global"),
+            Scope::Local => write!(f, "local"),
+        }
+    }
+}
+
 impl Into<Scope> for () {
     fn into(self) -> Scope {
         Scope::Global
@@ -1,5 +1,5 @@
 use crate::process::traversal::{Order, TraversalBuilder};
-use crate::structure::{GValue, T};
+use crate::structure::{Column, GValue, Lambda, T};
 
 pub struct ByStep {
     params: Vec<GValue>,
@@ -41,6 +41,18 @@ impl From<T> for ByStep {
     }
 }
 
+impl From<Column> for ByStep {
+    fn from(param: Column) -> Self {
+        ByStep::new(vec![param.into()])
+    }
+}
+
+impl From<(Column, Order)> for ByStep {
+    fn from(param: (Column, Order)) -> Self {
+        ByStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
+
 impl From<(&str, Order)> for ByStep {
     fn from(param: (&str, Order)) -> Self {
         ByStep::new(vec![param.0.into(), param.1.into()])
@@ -64,3 +76,21 @@ impl From<TraversalBuilder> for ByStep {
         ByStep::new(vec![param.bytecode.into()])
     }
 }
+
+impl From<Lambda> for ByStep {
+    fn from(param: Lambda) -> Self {
+        ByStep::new(vec![param.into()])
+    }
+}
+
+impl From<(&str, Lambda)> for ByStep {
+    fn from(param: (&str, Lambda)) -> Self {
+        ByStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
+
+impl From<(TraversalBuilder, Lambda)> for ByStep {
+    fn from(param: (TraversalBuilder, Lambda)) -> Self {
+        ByStep::new(vec![param.0.bytecode.into(), param.1.into()])
+    }
+}
@@ -0,0 +1,36 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::{GValue, List};
+
+pub struct CollectionStep {
+    params: Vec<GValue>,
+}
+
+impl CollectionStep {
+    fn new(params: Vec<GValue>) -> Self {
+        CollectionStep { params }
+    }
+}
+
+impl From<CollectionStep> for Vec<GValue> {
+    fn from(step: CollectionStep) -> Self {
+        step.params
+    }
+}
+
+impl From<Vec<GValue>> for CollectionStep {
+    fn from(param: Vec<GValue>) -> Self {
+        CollectionStep::new(vec![param.into()])
+    }
+}
+
+impl From<List> for CollectionStep {
+    fn from(param: List) -> Self {
+        CollectionStep::new(vec![GValue::List(param)])
+    }
+}
+
+impl From<TraversalBuilder> for CollectionStep {
+    fn from(param: TraversalBuilder) -> Self {
+        CollectionStep::new(vec![param.bytecode.into()])
+    }
+}
@@ -0,0 +1,48 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::GValue;
+
+pub struct ConcatStep {
+    params: Vec<GValue>,
+}
+
+impl ConcatStep {
+    fn new(params: Vec<GValue>) -> Self {
+        ConcatStep { params }
+    }
+}
+
+impl From<ConcatStep> for Vec<GValue> {
+    fn from(step: ConcatStep) -> Self {
+        step.params
+    }
+}
+
+impl From<&str> for ConcatStep {
+    fn from(param: &str) -> Self {
+        ConcatStep::new(vec![param.into()])
+    }
+}
+
+impl From<String> for ConcatStep {
+    fn from(param: String) -> Self {
+        ConcatStep::new(vec![param.into()])
+    }
+}
+
+impl From<Vec<&str>> for ConcatStep {
+    fn from(param: Vec<&str>) -> Self {
+        ConcatStep::new(param.into_iter().map(GValue::from).collect())
+    }
+}
+
+impl From<TraversalBuilder> for ConcatStep {
+    fn from(param: TraversalBuilder) -> Self {
+        ConcatStep::new(vec![param.bytecode.into()])
+    }
+}
+
+impl From<Vec<TraversalBuilder>> for ConcatStep {
+    fn from(param: Vec<TraversalBuilder>) -> Self {
+        ConcatStep::new(param.into_iter().map(|s| s.bytecode.into()).collect())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::GValue;
+
+pub struct DateDiffStep {
+    params: Vec<GValue>,
+}
+
+impl DateDiffStep {
+    fn new(params: Vec<GValue>) -> Self {
+        DateDiffStep { params }
+    }
+}
+
+impl From<DateDiffStep> for Vec<GValue> {
+    fn from(step: DateDiffStep) -> Self {
+        step.params
+    }
+}
+
+impl From<chrono::DateTime<chrono::Utc>> for DateDiffStep {
+    fn from(param: chrono::DateTime<chrono::Utc>) -> Self {
+        DateDiffStep::new(vec![param.into()])
+    }
+}
+
+impl From<TraversalBuilder> for DateDiffStep {
+    fn from(param: TraversalBuilder) -> Self {
+        DateDiffStep::new(vec![param.bytecode.into()])
+    }
+}
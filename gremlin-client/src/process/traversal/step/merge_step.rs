@@ -0,0 +1,43 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::{GKey, GValue, Map};
+use std::collections::HashMap;
+
+pub struct MergeStep {
+    params: Vec<GValue>,
+}
+
+impl MergeStep {
+    fn new(params: Vec<GValue>) -> Self {
+        MergeStep { params }
+    }
+}
+
+impl From<MergeStep> for Vec<GValue> {
+    fn from(step: MergeStep) -> Self {
+        step.params
+    }
+}
+
+impl From<()> for MergeStep {
+    fn from(_: ()) -> Self {
+        MergeStep::new(vec![])
+    }
+}
+
+impl From<Map> for MergeStep {
+    fn from(param: Map) -> Self {
+        MergeStep::new(vec![param.into()])
+    }
+}
+
+impl From<HashMap<GKey, GValue>> for MergeStep {
+    fn from(param: HashMap<GKey, GValue>) -> Self {
+        MergeStep::new(vec![param.into()])
+    }
+}
+
+impl From<TraversalBuilder> for MergeStep {
+    fn from(param: TraversalBuilder) -> Self {
+        MergeStep::new(vec![param.bytecode.into()])
+    }
+}
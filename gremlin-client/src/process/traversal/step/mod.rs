@@ -1,17 +1,28 @@
 pub mod by;
 pub mod choose;
 pub mod coalesce;
+pub mod collection;
+pub mod concat;
+pub mod date_diff;
 pub mod dedup;
+pub mod filter;
+pub mod flat_map;
 pub mod from;
 pub mod has;
 pub mod limit;
 pub mod local;
 pub mod loops;
 pub mod match_step;
+pub mod merge_step;
 pub mod not;
+pub mod option;
 pub mod or;
 pub mod repeat;
+pub mod sack;
 pub mod select;
+pub mod side_effect;
+pub mod substring;
 pub mod to;
 pub mod until;
 pub mod where_step;
+pub mod with;
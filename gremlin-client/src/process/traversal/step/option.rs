@@ -0,0 +1,55 @@
+use crate::process::traversal::{Merge, Pick, TraversalBuilder};
+use crate::structure::{GKey, GValue, Map};
+use std::collections::HashMap;
+
+pub struct OptionStep {
+    params: Vec<GValue>,
+}
+
+impl OptionStep {
+    fn new(params: Vec<GValue>) -> Self {
+        OptionStep { params }
+    }
+}
+
+impl From<OptionStep> for Vec<GValue> {
+    fn from(step: OptionStep) -> Self {
+        step.params
+    }
+}
+
+impl From<(Merge, Map)> for OptionStep {
+    fn from(param: (Merge, Map)) -> Self {
+        OptionStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
+
+impl From<(Merge, HashMap<GKey, GValue>)> for OptionStep {
+    fn from(param: (Merge, HashMap<GKey, GValue>)) -> Self {
+        OptionStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
+
+impl From<(Merge, TraversalBuilder)> for OptionStep {
+    fn from(param: (Merge, TraversalBuilder)) -> Self {
+        OptionStep::new(vec![param.0.into(), param.1.bytecode.into()])
+    }
+}
+
+impl From<(Pick, TraversalBuilder)> for OptionStep {
+    fn from(param: (Pick, TraversalBuilder)) -> Self {
+        OptionStep::new(vec![param.0.into(), param.1.bytecode.into()])
+    }
+}
+
+impl From<(&str, TraversalBuilder)> for OptionStep {
+    fn from(param: (&str, TraversalBuilder)) -> Self {
+        OptionStep::new(vec![param.0.into(), param.1.bytecode.into()])
+    }
+}
+
+impl From<(GValue, TraversalBuilder)> for OptionStep {
+    fn from(param: (GValue, TraversalBuilder)) -> Self {
+        OptionStep::new(vec![param.0, param.1.bytecode.into()])
+    }
+}
@@ -0,0 +1,30 @@
+use crate::process::traversal::Operator;
+use crate::structure::{GValue, Lambda};
+
+pub struct SackStep {
+    params: Vec<GValue>,
+}
+
+impl SackStep {
+    fn new(params: Vec<GValue>) -> Self {
+        SackStep { params }
+    }
+}
+
+impl From<SackStep> for Vec<GValue> {
+    fn from(step: SackStep) -> Self {
+        step.params
+    }
+}
+
+impl From<Lambda> for SackStep {
+    fn from(param: Lambda) -> Self {
+        SackStep::new(vec![param.into()])
+    }
+}
+
+impl From<Operator> for SackStep {
+    fn from(param: Operator) -> Self {
+        SackStep::new(vec![param.into()])
+    }
+}
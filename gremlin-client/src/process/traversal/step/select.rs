@@ -1,5 +1,5 @@
 use crate::process::traversal::TraversalBuilder;
-use crate::structure::{GValue, Pop};
+use crate::structure::{Column, GValue, Pop};
 
 pub struct SelectStep {
     params: Vec<GValue>,
@@ -29,6 +29,12 @@ impl From<Pop> for SelectStep {
     }
 }
 
+impl From<Column> for SelectStep {
+    fn from(param: Column) -> SelectStep {
+        SelectStep::new(vec![param.into()])
+    }
+}
+
 impl From<Vec<&str>> for SelectStep {
     fn from(param: Vec<&str>) -> SelectStep {
         SelectStep::new(param.into_iter().map(GValue::from).collect())
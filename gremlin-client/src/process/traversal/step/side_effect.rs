@@ -0,0 +1,30 @@
+use crate::process::traversal::TraversalBuilder;
+use crate::structure::{GValue, Lambda};
+
+pub struct SideEffectStep {
+    params: Vec<GValue>,
+}
+
+impl SideEffectStep {
+    fn new(params: Vec<GValue>) -> Self {
+        SideEffectStep { params }
+    }
+}
+
+impl From<SideEffectStep> for Vec<GValue> {
+    fn from(step: SideEffectStep) -> Self {
+        step.params
+    }
+}
+
+impl From<TraversalBuilder> for SideEffectStep {
+    fn from(param: TraversalBuilder) -> Self {
+        SideEffectStep::new(vec![param.bytecode.into()])
+    }
+}
+
+impl From<Lambda> for SideEffectStep {
+    fn from(param: Lambda) -> Self {
+        SideEffectStep::new(vec![param.into()])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::structure::GValue;
+
+pub struct SubstringStep {
+    params: Vec<GValue>,
+}
+
+impl SubstringStep {
+    fn new(params: Vec<GValue>) -> Self {
+        SubstringStep { params }
+    }
+}
+
+impl From<SubstringStep> for Vec<GValue> {
+    fn from(step: SubstringStep) -> Self {
+        step.params
+    }
+}
+
+impl From<i32> for SubstringStep {
+    fn from(start: i32) -> Self {
+        SubstringStep::new(vec![start.into()])
+    }
+}
+
+impl From<(i32, i32)> for SubstringStep {
+    fn from(param: (i32, i32)) -> Self {
+        SubstringStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
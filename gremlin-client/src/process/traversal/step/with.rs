@@ -0,0 +1,38 @@
+use crate::structure::GValue;
+
+pub struct WithStep {
+    params: Vec<GValue>,
+}
+
+impl WithStep {
+    fn new(params: Vec<GValue>) -> Self {
+        WithStep { params }
+    }
+}
+
+impl From<WithStep> for Vec<GValue> {
+    fn from(step: WithStep) -> Self {
+        step.params
+    }
+}
+
+impl From<&str> for WithStep {
+    fn from(key: &str) -> Self {
+        WithStep::new(vec![key.into()])
+    }
+}
+
+impl From<String> for WithStep {
+    fn from(key: String) -> Self {
+        WithStep::new(vec![key.into()])
+    }
+}
+
+impl<V> From<(&str, V)> for WithStep
+where
+    V: Into<GValue>,
+{
+    fn from(param: (&str, V)) -> Self {
+        WithStep::new(vec![param.0.into(), param.1.into()])
+    }
+}
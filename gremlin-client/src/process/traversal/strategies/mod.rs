@@ -1,56 +1,169 @@
+mod read_only;
 mod remote;
-use crate::conversion::FromGValue;
-use crate::process::traversal::remote::Terminator;
-use crate::process::traversal::GraphTraversal;
-use crate::process::traversal::RemoteTraversalIterator;
-use crate::{GremlinError, GremlinResult};
+mod tenant;
+use crate::process::traversal::Bytecode;
+use crate::GremlinResult;
+pub use read_only::ReadOnlyStrategy;
 pub use remote::RemoteStrategy;
+#[cfg(feature = "async_gremlin")]
+pub(crate) use remote::EXPLAIN_SCRIPT;
+use std::sync::Arc;
+pub use tenant::TenantStrategy;
 
-#[derive(Clone)]
-pub enum TraversalStrategy {
-    Remote(RemoteStrategy),
+/// Categories of [TraversalStrategy](trait.TraversalStrategy). Strategies are applied category
+/// by category in declaration order, and in registration order within the same category.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum StrategyCategory {
+    Decoration,
+    Optimization,
+    Finalization,
+    Verification,
 }
 
-impl TraversalStrategy {
-    pub(crate) fn apply<S, E: FromGValue, A>(
-        &self,
-        traversal: &GraphTraversal<S, E, A>,
-    ) -> GremlinResult<RemoteTraversalIterator<E>>
-    where
-        A: Terminator<E>,
-    {
-        match self {
-            TraversalStrategy::Remote(x) => x.apply(traversal),
-        }
+/// A client-side strategy that can rewrite or reject a traversal's [Bytecode](struct.Bytecode)
+/// before it is submitted to the server.
+pub trait TraversalStrategy: Send + Sync {
+    fn category(&self) -> StrategyCategory {
+        StrategyCategory::Decoration
     }
+
+    fn apply(&self, bytecode: Bytecode) -> GremlinResult<Bytecode>;
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TraversalStrategies {
-    strategies: Vec<TraversalStrategy>,
+    strategies: Vec<Arc<dyn TraversalStrategy>>,
 }
 
 impl TraversalStrategies {
-    pub(crate) fn new(strategies: Vec<TraversalStrategy>) -> TraversalStrategies {
-        TraversalStrategies { strategies }
-    }
-    pub(crate) fn add_strategy(&mut self, strategy: TraversalStrategy) {
-        self.strategies.push(strategy);
+    pub fn new() -> TraversalStrategies {
+        Default::default()
     }
 
-    pub(crate) fn apply<S, E: FromGValue, A>(
-        &self,
-        traversal: &GraphTraversal<S, E, A>,
-    ) -> GremlinResult<RemoteTraversalIterator<E>>
+    pub fn add_strategy<S>(&mut self, strategy: S)
     where
-        A: Terminator<E>,
+        S: TraversalStrategy + 'static,
     {
-        let mut iter = None;
-        for strategy in self.strategies.iter() {
-            iter = Some(strategy.apply(traversal)?);
+        let strategy: Arc<dyn TraversalStrategy> = Arc::new(strategy);
+        // stable insertion keeps registration order within a category
+        let position = self
+            .strategies
+            .iter()
+            .position(|s| s.category() > strategy.category())
+            .unwrap_or(self.strategies.len());
+        self.strategies.insert(position, strategy);
+    }
+
+    pub fn apply(&self, bytecode: &Bytecode) -> GremlinResult<Bytecode> {
+        self.strategies
+            .iter()
+            .try_fold(bytecode.clone(), |code, strategy| strategy.apply(code))
+    }
+}
+
+// TESTS
+#[cfg(test)]
+mod tests {
+
+    use super::{
+        ReadOnlyStrategy, StrategyCategory, TenantStrategy, TraversalStrategies, TraversalStrategy,
+    };
+    use crate::process::traversal::{traversal, Bytecode, __};
+    use crate::structure::P;
+    use crate::GremlinResult;
+
+    struct AddStep(&'static str, StrategyCategory);
+
+    impl TraversalStrategy for AddStep {
+        fn category(&self) -> StrategyCategory {
+            self.1
+        }
+
+        fn apply(&self, mut bytecode: Bytecode) -> GremlinResult<Bytecode> {
+            bytecode.add_step(String::from(self.0), vec![]);
+            Ok(bytecode)
         }
+    }
+
+    #[test]
+    fn strategies_order_test() {
+        let mut strategies = TraversalStrategies::new();
+        strategies.add_strategy(AddStep("verification", StrategyCategory::Verification));
+        strategies.add_strategy(AddStep("decoration", StrategyCategory::Decoration));
+        strategies.add_strategy(AddStep("optimization", StrategyCategory::Optimization));
+        strategies.add_strategy(AddStep("decoration2", StrategyCategory::Decoration));
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("decoration"), vec![]);
+        code.add_step(String::from("decoration2"), vec![]);
+        code.add_step(String::from("optimization"), vec![]);
+        code.add_step(String::from("verification"), vec![]);
+
+        assert_eq!(
+            code,
+            strategies
+                .apply(&Bytecode::new())
+                .expect("Failed to apply strategies")
+        );
+    }
+
+    #[test]
+    fn tenant_strategy_test() {
+        let g = traversal().empty();
+
+        let mut strategies = TraversalStrategies::new();
+        strategies.add_strategy(TenantStrategy::new("tenant", "acme"));
+
+        let mut nested = Bytecode::new();
+
+        nested.add_step(String::from("V"), vec![]);
+        nested.add_step(
+            String::from("has"),
+            vec!["tenant".into(), P::eq("acme").into()],
+        );
+
+        let mut code = Bytecode::new();
+
+        code.add_step(String::from("V"), vec![]);
+        code.add_step(
+            String::from("has"),
+            vec!["tenant".into(), P::eq("acme").into()],
+        );
+        code.add_step(String::from("outE"), vec![]);
+        code.add_step(String::from("where"), vec![nested.into()]);
+
+        assert_eq!(
+            code,
+            strategies
+                .apply(g.v(()).out_e(()).where_(__.v(())).bytecode())
+                .expect("Failed to apply strategies")
+        );
+    }
+
+    #[test]
+    fn read_only_strategy_test() {
+        let g = traversal().empty();
+
+        let mut strategies = TraversalStrategies::new();
+        strategies.add_strategy(ReadOnlyStrategy::new());
+        strategies.add_strategy(TenantStrategy::new("tenant", "acme"));
 
-        iter.map(Ok)
-            .unwrap_or_else(|| Err(GremlinError::Generic(String::from("Empty traversal"))))
+        assert!(strategies.apply(g.v(()).out(()).bytecode()).is_ok());
+        assert!(strategies.apply(g.v(()).drop().bytecode()).is_err());
+        assert!(strategies
+            .apply(g.v(()).local(__.add_v("person")).bytecode())
+            .is_err());
+        assert!(strategies.apply(g.merge_v(()).bytecode()).is_err());
+        assert!(strategies
+            .apply(g.v(()).local(__.merge_e(())).bytecode())
+            .is_err());
+        assert!(g.merge_v(()).does_write());
+        assert!(strategies
+            .apply(g.io("data.xml").read().bytecode())
+            .is_err());
+        assert!(strategies
+            .apply(g.io("data.xml").write().bytecode())
+            .is_ok());
     }
 }
@@ -0,0 +1,42 @@
+use crate::process::traversal::strategies::{StrategyCategory, TraversalStrategy};
+use crate::process::traversal::{Bytecode, WRITE_OPERATORS};
+use crate::{GValue, GremlinError, GremlinResult};
+
+/// Reject any traversal, including nested anonymous traversals, that contains one of the
+/// [WRITE_OPERATORS](static.WRITE_OPERATORS).
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlyStrategy {}
+
+impl ReadOnlyStrategy {
+    pub fn new() -> ReadOnlyStrategy {
+        ReadOnlyStrategy {}
+    }
+}
+
+impl TraversalStrategy for ReadOnlyStrategy {
+    fn category(&self) -> StrategyCategory {
+        StrategyCategory::Verification
+    }
+
+    fn apply(&self, bytecode: Bytecode) -> GremlinResult<Bytecode> {
+        match find_write(&bytecode) {
+            Some(operator) => Err(GremlinError::Generic(format!(
+                "The traversal is read-only but contains the write step {}",
+                operator
+            ))),
+            None => Ok(bytecode),
+        }
+    }
+}
+
+fn find_write(bytecode: &Bytecode) -> Option<&String> {
+    bytecode.steps().iter().find_map(|instruction| {
+        if WRITE_OPERATORS.contains(&instruction.operator().as_str()) {
+            return Some(instruction.operator());
+        }
+        instruction.args().iter().find_map(|arg| match arg {
+            GValue::Bytecode(nested) => find_write(nested),
+            _ => None,
+        })
+    })
+}
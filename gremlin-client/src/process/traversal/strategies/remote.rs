@@ -1,11 +1,15 @@
 use crate::conversion::FromGValue;
+use crate::structure::TraversalExplanation;
 
-use crate::process::traversal::remote::Terminator;
 use crate::{
-    process::traversal::GraphTraversal, process::traversal::RemoteTraversalIterator, GremlinClient,
-    GremlinResult,
+    process::traversal::Bytecode, process::traversal::RemoteTraversalIterator, GremlinClient,
+    GremlinError, GremlinResult,
 };
 
+// Bytecode has no explain step, so the traversal is rebuilt and explained server side.
+pub(crate) const EXPLAIN_SCRIPT: &str =
+    "org.apache.tinkerpop.gremlin.jsr223.JavaTranslator.of(g).translate(bytecode).explain()";
+
 #[derive(Clone)]
 pub struct RemoteStrategy {
     client: GremlinClient,
@@ -16,15 +20,21 @@ impl RemoteStrategy {
         RemoteStrategy { client }
     }
 
-    pub(crate) fn apply<S, E: FromGValue, A>(
+    pub(crate) fn apply<E: FromGValue>(
         &self,
-        traversal: &GraphTraversal<S, E, A>,
-    ) -> GremlinResult<RemoteTraversalIterator<E>>
-    where
-        A: Terminator<E>,
-    {
-        let result = self.client.submit_traversal(traversal.bytecode())?;
+        bytecode: &Bytecode,
+    ) -> GremlinResult<RemoteTraversalIterator<E>> {
+        let result = self.client.submit_traversal(bytecode)?;
 
         Ok(RemoteTraversalIterator::new(result))
     }
+
+    pub(crate) fn explain(&self, bytecode: &Bytecode) -> GremlinResult<TraversalExplanation> {
+        self.client
+            .execute(EXPLAIN_SCRIPT, &[("bytecode", bytecode)])?
+            .next()
+            .transpose()?
+            .ok_or_else(|| GremlinError::Generic(String::from("Missing traversal explanation")))?
+            .take::<TraversalExplanation>()
+    }
 }
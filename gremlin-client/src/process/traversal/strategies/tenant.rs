@@ -0,0 +1,62 @@
+use crate::process::traversal::strategies::TraversalStrategy;
+use crate::process::traversal::Bytecode;
+use crate::structure::P;
+use crate::{GValue, GremlinResult};
+
+/// Filter every `V()` and `E()` step, including those in nested anonymous traversals, with
+/// `has(key, value)`.
+#[derive(Debug, Clone)]
+pub struct TenantStrategy {
+    key: String,
+    value: GValue,
+}
+
+impl TenantStrategy {
+    pub fn new<K, V>(key: K, value: V) -> TenantStrategy
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        TenantStrategy {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    fn rewrite(&self, bytecode: &Bytecode) -> Bytecode {
+        let mut code = Bytecode::new();
+
+        for source in bytecode.sources() {
+            code.add_source(source.operator().clone(), source.args().clone());
+        }
+
+        for step in bytecode.steps() {
+            let args = step
+                .args()
+                .iter()
+                .map(|arg| match arg {
+                    GValue::Bytecode(nested) => GValue::Bytecode(self.rewrite(nested)),
+                    _ => arg.clone(),
+                })
+                .collect();
+            code.add_step(step.operator().clone(), args);
+
+            if step.operator() == "V" || step.operator() == "E" {
+                code.add_step(
+                    String::from("has"),
+                    vec![
+                        self.key.clone().into(),
+                        P::new("eq", self.value.clone()).into(),
+                    ],
+                );
+            }
+        }
+        code
+    }
+}
+
+impl TraversalStrategy for TenantStrategy {
+    fn apply(&self, bytecode: Bytecode) -> GremlinResult<Bytecode> {
+        Ok(self.rewrite(&bytecode))
+    }
+}
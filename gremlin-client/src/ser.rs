@@ -0,0 +1,394 @@
+use crate::structure::{GKey, GValue, List, Map};
+use crate::{GremlinError, GremlinResult};
+use serde::ser::{self, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+/// Serialize a type implementing `serde::Serialize` into a [GValue](enum.GValue.html),
+/// e.g. to use it as a script parameter or as a property map.
+///
+/// Structs and maps become a `Map`, sequences a `List`, unit variants their name and other
+/// enum variants a `Map` with a single entry keyed by the variant name.
+pub fn to_gvalue<T>(value: &T) -> GremlinResult<GValue>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Serializer)
+}
+
+impl ser::Error for GremlinError {
+    fn custom<M: Display>(msg: M) -> Self {
+        GremlinError::Cast(msg.to_string())
+    }
+}
+
+struct Serializer;
+
+fn variant_map(variant: &'static str, value: GValue) -> GValue {
+    let mut map = HashMap::new();
+    map.insert(GKey::String(String::from(variant)), value);
+    GValue::Map(Map::from(map))
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> GremlinResult<GValue> {
+        Ok(GValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> GremlinResult<GValue> {
+        Ok(GValue::Int32(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> GremlinResult<GValue> {
+        Ok(GValue::Int32(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> GremlinResult<GValue> {
+        Ok(GValue::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> GremlinResult<GValue> {
+        Ok(GValue::Int64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> GremlinResult<GValue> {
+        Ok(GValue::Int32(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> GremlinResult<GValue> {
+        Ok(GValue::Int32(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> GremlinResult<GValue> {
+        Ok(GValue::Int64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> GremlinResult<GValue> {
+        i64::try_from(v)
+            .map(GValue::Int64)
+            .map_err(|_| GremlinError::Cast(format!("{} does not fit in a g:Int64", v)))
+    }
+
+    fn serialize_f32(self, v: f32) -> GremlinResult<GValue> {
+        Ok(GValue::Float(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> GremlinResult<GValue> {
+        Ok(GValue::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> GremlinResult<GValue> {
+        Ok(GValue::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> GremlinResult<GValue> {
+        Ok(GValue::String(String::from(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> GremlinResult<GValue> {
+        Ok(GValue::List(List::new(
+            v.iter().map(|b| GValue::Int32((*b).into())).collect(),
+        )))
+    }
+
+    fn serialize_none(self) -> GremlinResult<GValue> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> GremlinResult<GValue>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> GremlinResult<GValue> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> GremlinResult<GValue> {
+        Ok(GValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> GremlinResult<GValue> {
+        Ok(GValue::String(String::from(variant)))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> GremlinResult<GValue>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> GremlinResult<GValue>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(variant_map(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> GremlinResult<SerializeList> {
+        Ok(SerializeList {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> GremlinResult<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> GremlinResult<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> GremlinResult<SerializeList> {
+        Ok(SerializeList {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> GremlinResult<SerializeMap> {
+        Ok(SerializeMap {
+            variant: None,
+            entries: HashMap::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> GremlinResult<SerializeMap> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> GremlinResult<SerializeMap> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            entries: HashMap::new(),
+            key: None,
+        })
+    }
+}
+
+struct SerializeList {
+    variant: Option<&'static str>,
+    values: Vec<GValue>,
+}
+
+impl SerializeList {
+    fn push<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.values.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> GremlinResult<GValue> {
+        let list = GValue::List(List::new(self.values));
+        Ok(match self.variant {
+            Some(variant) => variant_map(variant, list),
+            None => list,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+struct SerializeMap {
+    variant: Option<&'static str>,
+    entries: HashMap<GKey, GValue>,
+    key: Option<GKey>,
+}
+
+impl SerializeMap {
+    fn insert<T>(&mut self, key: GKey, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.entries.insert(key, value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> GremlinResult<GValue> {
+        let map = GValue::Map(Map::from(self.entries));
+        Ok(match self.variant {
+            Some(variant) => variant_map(variant, map),
+            None => map,
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key = match key.serialize(Serializer)? {
+            GValue::String(s) => Some(GKey::String(s)),
+            GValue::Int32(i) => Some(GKey::String(i.to_string())),
+            GValue::Int64(i) => Some(GKey::String(i.to_string())),
+            GValue::Bool(b) => Some(GKey::String(b.to_string())),
+            other => {
+                return Err(GremlinError::Cast(format!(
+                    "Map keys must be strings, found {:?}",
+                    other
+                )))
+            }
+        };
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self.key.take() {
+            Some(key) => self.insert(key, value),
+            None => Err(GremlinError::Cast(String::from(
+                "Map value serialized before its key",
+            ))),
+        }
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(GKey::String(String::from(key)), value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = GValue;
+    type Error = GremlinError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> GremlinResult<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(GKey::String(String::from(key)), value)
+    }
+
+    fn end(self) -> GremlinResult<GValue> {
+        self.finish()
+    }
+}
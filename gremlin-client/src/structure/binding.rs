@@ -0,0 +1,38 @@
+use crate::structure::GValue;
+use std::fmt;
+
+/// A named traversal argument. The server caches the compiled traversal and only
+/// substitutes the bound value on later submissions.
+/// TinkerPop type [here](http://tinkerpop.apache.org/docs/current/dev/io/#_binding_2)
+#[derive(Debug, PartialEq, Clone)]
+pub struct Binding {
+    key: String,
+    value: Box<GValue>,
+}
+
+impl Binding {
+    pub fn new<K, V>(key: K, value: V) -> Binding
+    where
+        K: Into<String>,
+        V: Into<GValue>,
+    {
+        Binding {
+            key: key.into(),
+            value: Box::new(value.into()),
+        }
+    }
+
+    pub fn key(&self) -> &String {
+        &self.key
+    }
+
+    pub fn value(&self) -> &GValue {
+        &self.value
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "binding[{}={}]", self.key, self.value)
+    }
+}
@@ -1,6 +1,18 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Cardinality {
     List,
     Set,
     Single,
 }
+
+impl Display for Cardinality {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Cardinality::List => write!(f, "list"),
+            Cardinality::Set => write!(f, "set"),
+            Cardinality::Single => write!(f, "single"),
+        }
+    }
+}
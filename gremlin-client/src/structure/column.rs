@@ -0,0 +1,16 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Column {
+    Keys,
+    Values,
+}
+
+impl Display for Column {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Column::Keys => write!(f, "keys"),
+            Column::Values => write!(f, "values"),
+        }
+    }
+}
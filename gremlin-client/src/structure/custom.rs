@@ -0,0 +1,49 @@
+use serde_json::Value;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A provider specific value with no dedicated type in the driver, e.g. a
+/// `janusgraph:RelationIdentifier`, kept as its GraphSON type and raw value so that it
+/// is written back unchanged.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Custom {
+    type_name: String,
+    value: Value,
+}
+
+impl Custom {
+    pub fn new<T>(type_name: T, value: Value) -> Custom
+    where
+        T: Into<String>,
+    {
+        Custom {
+            type_name: type_name.into(),
+            value,
+        }
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+// JSON objects keep their keys sorted, equal values write the same string
+impl Hash for Custom {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_name.hash(state);
+        self.value.to_string().hash(state);
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Value::String(s) => write!(f, "{}", s),
+            value => write!(f, "{}", value),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Direction {
+    Out,
+    In,
+    Both,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Direction::Out => write!(f, "OUT"),
+            Direction::In => write!(f, "IN"),
+            Direction::Both => write!(f, "BOTH"),
+        }
+    }
+}
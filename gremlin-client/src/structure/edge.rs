@@ -1,6 +1,7 @@
 use crate::structure::{Property, Vertex, GID};
 use std::collections::hash_map::{IntoIter, Iter};
 use std::collections::HashMap;
+use std::fmt;
 use std::hash::Hasher;
 
 #[derive(Debug, Clone)]
@@ -13,7 +14,7 @@ pub struct Edge {
 }
 
 impl Edge {
-    pub(crate) fn new<T>(
+    pub fn new<T>(
         id: GID,
         label: T,
         in_v_id: GID,
@@ -34,6 +35,20 @@ impl Edge {
         }
     }
 
+    pub fn builder<G, T>(id: G, label: T, out_v: Vertex, in_v: Vertex) -> EdgeBuilder
+    where
+        G: Into<GID>,
+        T: Into<String>,
+    {
+        EdgeBuilder(Edge {
+            id: id.into(),
+            label: label.into(),
+            in_v,
+            out_v,
+            properties: HashMap::new(),
+        })
+    }
+
     pub fn id(&self) -> &GID {
         &self.id
     }
@@ -56,6 +71,32 @@ impl Edge {
     pub fn property(&self, key: &str) -> Option<&Property> {
         self.properties.get(key)
     }
+
+    pub fn property_mut(&mut self, key: &str) -> Option<&mut Property> {
+        self.properties.get_mut(key)
+    }
+
+    /// Insert a property keyed by its label, returning the replaced one if any
+    pub fn add_property(&mut self, property: Property) -> Option<Property> {
+        self.properties.insert(property.label().clone(), property)
+    }
+
+    pub fn remove_property(&mut self, key: &str) -> Option<Property> {
+        self.properties.remove(key)
+    }
+}
+
+pub struct EdgeBuilder(Edge);
+
+impl EdgeBuilder {
+    pub fn property(mut self, property: Property) -> Self {
+        self.0.add_property(property);
+        self
+    }
+
+    pub fn build(self) -> Edge {
+        self.0
+    }
 }
 
 impl IntoIterator for Edge {
@@ -79,3 +120,16 @@ impl std::hash::Hash for Edge {
         self.id.hash(state);
     }
 }
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "e[{}][{}-{}->{}]",
+            self.id,
+            self.out_v.id(),
+            self.label,
+            self.in_v.id()
+        )
+    }
+}
@@ -1,4 +1,9 @@
+use crate::janusgraph::RelationIdentifier;
+use crate::structure::GValue;
 use crate::{GremlinError, GremlinResult};
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -22,11 +27,33 @@ impl From<()> for GIDs {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum GID {
     String(String),
     Int32(i32),
     Int64(i64),
+    Uuid(Uuid),
+    /// A provider specific id, e.g. a JanusGraph [RelationIdentifier](../janusgraph/struct.RelationIdentifier.html)
+    Custom(Box<GValue>),
+}
+
+impl Eq for GID {}
+
+impl Hash for GID {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            GID::String(s) => s.hash(state),
+            GID::Int32(n) => n.hash(state),
+            GID::Int64(n) => n.hash(state),
+            GID::Uuid(u) => u.hash(state),
+            GID::Custom(v) => match v.as_ref() {
+                GValue::Custom(c) => c.hash(state),
+                // other values are not hashable, they only collide on their variant
+                other => std::mem::discriminant(other).hash(state),
+            },
+        }
+    }
 }
 
 impl GID {
@@ -38,6 +65,21 @@ impl GID {
     }
 }
 
+impl fmt::Display for GID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GID::String(s) => write!(f, "{}", s),
+            GID::Int32(n) => write!(f, "{}", n),
+            GID::Int64(n) => write!(f, "{}", n),
+            GID::Uuid(u) => write!(f, "{}", u),
+            GID::Custom(v) => match RelationIdentifier::try_from(self) {
+                Ok(id) => write!(f, "{}", id),
+                Err(_) => write!(f, "{}", v),
+            },
+        }
+    }
+}
+
 impl From<&'static str> for GID {
     fn from(val: &str) -> Self {
         GID::String(String::from(val))
@@ -70,7 +112,7 @@ impl From<&GID> for GID {
 
 impl From<Uuid> for GID {
     fn from(val: Uuid) -> Self {
-        GID::String(val.to_string())
+        GID::Uuid(val)
     }
 }
 
@@ -101,3 +143,5 @@ macro_rules! impl_borrow_from_gid {
 impl_borrow_from_gid!(String, GID::String);
 impl_borrow_from_gid!(i32, GID::Int32);
 impl_borrow_from_gid!(i64, GID::Int64);
+impl_borrow_from_gid!(Uuid, GID::Uuid);
+impl_borrow_from_gid!(GValue, GID::Custom);
@@ -0,0 +1,277 @@
+use crate::conversion::ToGValue;
+use crate::process::traversal::Instruction;
+use crate::structure::value::format_date;
+use crate::structure::{Edge, GValue, Map, Metric, Tree, Vertex};
+use serde_json::{json, Number, Value};
+
+impl GValue {
+    /// Converts the value into JSON without GraphSON type information.
+    ///
+    /// Elements are written as objects, e.g. `{"id":1,"label":"person","properties":{"name":["marko"]}}`
+    /// for a vertex, dates as RFC 3339 strings and map keys as their textual representation.
+    pub fn to_plain_json(&self) -> Value {
+        match self {
+            GValue::Null => Value::Null,
+            GValue::Vertex(v) => vertex(v),
+            GValue::Edge(e) => edge(e),
+            GValue::VertexProperty(p) => json!({
+                "id" : p.id().to_gvalue().to_plain_json(),
+                "label" : p.label(),
+                "value" : p.value().to_plain_json(),
+            }),
+            GValue::Property(p) => json!({
+                "key" : p.label(),
+                "value" : p.value().to_plain_json(),
+            }),
+            GValue::Uuid(u) => Value::String(u.to_string()),
+            GValue::Int32(n) => json!(n),
+            GValue::Int64(n) => json!(n),
+            // going through the shortest representation avoids widening artifacts, e.g. 0.1f32
+            GValue::Float(n) => float(n.to_string().parse().unwrap_or(f64::NAN)),
+            GValue::Double(n) => float(*n),
+            GValue::Date(d) => Value::String(format_date(d)),
+            GValue::List(l) => l.iter().map(GValue::to_plain_json).collect(),
+            GValue::Set(s) => s.iter().map(GValue::to_plain_json).collect(),
+            GValue::Map(m) => map(m),
+            GValue::Token(t) => Value::String(t.value().clone()),
+            GValue::String(s) => Value::String(s.clone()),
+            GValue::Path(p) => json!({
+                "labels" : p.labels(),
+                "objects" : p.objects().iter().map(GValue::to_plain_json).collect::<Value>(),
+            }),
+            GValue::TraversalMetrics(m) => json!({
+                "dur" : float(*m.duration()),
+                "metrics" : m.metrics().iter().map(metric).collect::<Value>(),
+            }),
+            GValue::Metric(m) => metric(m),
+            GValue::TraversalExplanation(e) => json!({
+                "original" : e.original(),
+                "intermediate" : e.intermediate().iter().map(|i| json!({
+                    "strategy" : i.strategy(),
+                    "category" : i.category(),
+                    "traversal" : i.traversal(),
+                })).collect::<Value>(),
+                "final" : e.final_t(),
+            }),
+            GValue::IntermediateRepr(i) => json!({
+                "strategy" : i.strategy(),
+                "category" : i.category(),
+                "traversal" : i.traversal(),
+            }),
+            GValue::P(p) => json!({
+                "predicate" : p.operator(),
+                "value" : p.value().to_plain_json(),
+            }),
+            GValue::TextP(p) => json!({
+                "predicate" : p.operator(),
+                "value" : p.value().to_plain_json(),
+            }),
+            GValue::Bytecode(code) => json!({
+                "source" : instructions(code.sources()),
+                "step" : instructions(code.steps()),
+            }),
+            GValue::Traverser(t) => json!({
+                "bulk" : t.bulk(),
+                "value" : t.get().to_plain_json(),
+            }),
+            GValue::Bool(b) => Value::Bool(*b),
+            GValue::Lambda(l) => json!({
+                "script" : l.script(),
+                "language" : l.language(),
+                "arguments" : l.arguments(),
+            }),
+            GValue::Binding(b) => json!({
+                "key" : b.key(),
+                "value" : b.value().to_plain_json(),
+            }),
+            GValue::Custom(c) => c.value().clone(),
+            GValue::Tree(t) => tree(t),
+            GValue::T(_)
+            | GValue::Scope(_)
+            | GValue::Order(_)
+            | GValue::Pop(_)
+            | GValue::Cardinality(_)
+            | GValue::Direction(_)
+            | GValue::Merge(_)
+            | GValue::DT(_)
+            | GValue::Column(_)
+            | GValue::Operator(_)
+            | GValue::Barrier(_)
+            | GValue::Pick(_) => Value::String(self.to_string()),
+        }
+    }
+}
+
+// JSON has no NaN nor infinities, they are written as strings
+fn float(n: f64) -> Value {
+    Number::from_f64(n)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(n.to_string()))
+}
+
+fn vertex(v: &Vertex) -> Value {
+    let mut value = json!({
+        "id" : v.id().to_gvalue().to_plain_json(),
+        "label" : v.label(),
+    });
+    if v.iter().next().is_some() {
+        value["properties"] = v
+            .iter()
+            .map(|(key, properties)| {
+                let values = properties.iter().map(|p| p.value().to_plain_json());
+                (key.clone(), values.collect())
+            })
+            .collect::<serde_json::Map<String, Value>>()
+            .into();
+    }
+    value
+}
+
+fn edge(e: &Edge) -> Value {
+    let mut value = json!({
+        "id" : e.id().to_gvalue().to_plain_json(),
+        "label" : e.label(),
+        "inV" : e.in_v().id().to_gvalue().to_plain_json(),
+        "inVLabel" : e.in_v().label(),
+        "outV" : e.out_v().id().to_gvalue().to_plain_json(),
+        "outVLabel" : e.out_v().label(),
+    });
+    if e.iter().next().is_some() {
+        value["properties"] = e
+            .iter()
+            .map(|(key, property)| (key.clone(), property.value().to_plain_json()))
+            .collect::<serde_json::Map<String, Value>>()
+            .into();
+    }
+    value
+}
+
+fn map(m: &Map) -> Value {
+    m.iter()
+        .map(|(k, v)| (k.to_string(), v.to_plain_json()))
+        .collect::<serde_json::Map<String, Value>>()
+        .into()
+}
+
+fn metric(m: &Metric) -> Value {
+    let mut annotations = match map(m.annotations()) {
+        Value::Object(annotations) => annotations,
+        _ => serde_json::Map::new(),
+    };
+    annotations.insert(String::from("percentDur"), float(*m.perc_duration()));
+
+    json!({
+        "id" : m.id(),
+        "name" : m.name(),
+        "dur" : float(*m.duration()),
+        "counts" : {
+            "traverserCount" : m.traversers(),
+            "elementCount" : m.count(),
+        },
+        "annotations" : annotations,
+        "metrics" : m.nested().iter().map(metric).collect::<Value>(),
+    })
+}
+
+fn tree(t: &Tree) -> Value {
+    t.branches()
+        .iter()
+        .map(|branch| {
+            json!({
+                "key" : branch.key().to_plain_json(),
+                "value" : tree(branch.tree()),
+            })
+        })
+        .collect()
+}
+
+fn instructions(instructions: &[Instruction]) -> Value {
+    instructions
+        .iter()
+        .map(|i| {
+            std::iter::once(Value::String(i.operator().clone()))
+                .chain(i.args().iter().map(GValue::to_plain_json))
+                .collect::<Value>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::structure::{Branch, GValue, Map, Path, Tree, Vertex, VertexProperty, P, T};
+    use crate::{edge, vertex};
+    use chrono::offset::TimeZone;
+    use chrono::Utc;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn plain_json_of_elements() {
+        let v = Vertex::builder(1, "person")
+            .property(VertexProperty::new(0i64, "name", "marko"))
+            .build();
+        assert_eq!(
+            json!({"id": 1, "label": "person", "properties": {"name": ["marko"]}}),
+            GValue::from(v).to_plain_json()
+        );
+
+        let e: GValue = edge!({
+            id => 7,
+            label => "knows",
+            inV => { id => 2, label => "person" },
+            outV => { id => 1, label => "person" },
+            properties => {}
+        })
+        .into();
+        assert_eq!(
+            json!({"id": 7, "label": "knows", "inV": 2, "inVLabel": "person", "outV": 1, "outVLabel": "person"}),
+            e.to_plain_json()
+        );
+
+        let path = Path::builder()
+            .step(
+                vec!["a"],
+                vertex!({ id => 1, label => "person", properties => {}}),
+            )
+            .step(Vec::<String>::new(), "josh")
+            .build();
+        assert_eq!(
+            json!({"labels": [["a"], []], "objects": [{"id": 1, "label": "person"}, "josh"]}),
+            GValue::from(path).to_plain_json()
+        );
+    }
+
+    #[test]
+    fn plain_json_of_values() {
+        let mut m = HashMap::new();
+        m.insert(String::from("age"), GValue::from(29));
+        m.insert(String::from("weight"), GValue::from(0.1f32));
+        m.insert(
+            String::from("created"),
+            GValue::from(Utc.timestamp_millis(1_500_000_000_000)),
+        );
+        m.insert(
+            String::from("scores"),
+            vec![f64::NAN.into(), GValue::Null].into(),
+        );
+        m.insert(String::from("by"), T::Label.into());
+        m.insert(String::from("where"), P::gt(10).into());
+        assert_eq!(
+            json!({
+                "age": 29,
+                "weight": 0.1,
+                "created": "2017-07-14T02:40:00.000Z",
+                "scores": ["NaN", null],
+                "by": "label",
+                "where": {"predicate": "gt", "value": 10}
+            }),
+            GValue::from(Map::from(m)).to_plain_json()
+        );
+
+        let tree = Tree::new(vec![Branch::new("marko", Tree::default())]);
+        assert_eq!(
+            json!([{"key": "marko", "value": []}]),
+            GValue::from(tree).to_plain_json()
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// A script function evaluated by the server, e.g. a Groovy closure.
+/// TinkerPop type [here](http://tinkerpop.apache.org/docs/current/dev/io/#_lambda_2)
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lambda {
+    script: String,
+    language: String,
+    arguments: i32,
+}
+
+impl Lambda {
+    /// Create a `gremlin-groovy` lambda
+    pub fn new<T>(script: T) -> Lambda
+    where
+        T: Into<String>,
+    {
+        Lambda::with_language(script, "gremlin-groovy")
+    }
+
+    pub fn with_language<T, L>(script: T, language: L) -> Lambda
+    where
+        T: Into<String>,
+        L: Into<String>,
+    {
+        Lambda {
+            script: script.into(),
+            language: language.into(),
+            arguments: -1,
+        }
+    }
+
+    /// Set the number of arguments the function takes. Defaults to -1 (unknown).
+    pub fn with_arguments(mut self, arguments: i32) -> Lambda {
+        self.arguments = arguments;
+        self
+    }
+
+    pub fn script(&self) -> &String {
+        &self.script
+    }
+
+    pub fn language(&self) -> &String {
+        &self.language
+    }
+
+    pub fn arguments(&self) -> i32 {
+        self.arguments
+    }
+}
+
+impl fmt::Display for Lambda {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.script)
+    }
+}
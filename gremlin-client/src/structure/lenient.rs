@@ -0,0 +1,143 @@
+use crate::conversion::FromGValue;
+use crate::structure::GValue;
+use crate::{GremlinError, GremlinResult};
+use std::convert::TryFrom;
+
+/// Opt-in lenient numeric conversion.
+///
+/// The default conversions only widen losslessly, `Lenient<T>` instead reads any numeric
+/// `GValue` as `T` as long as the value is in range, e.g. an `Int64` count as `u32` or a
+/// `Double` sum as `i64`. Fractional parts are dropped when reading integers and values are
+/// rounded when reading `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Lenient<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+fn number(value: GValue, target: &str) -> GremlinResult<Number> {
+    match value {
+        GValue::Int32(n) => Ok(Number::Int(n.into())),
+        GValue::Int64(n) => Ok(Number::Int(n)),
+        GValue::Float(n) => Ok(Number::Float(n.into())),
+        GValue::Double(n) => Ok(Number::Float(n)),
+        GValue::List(list) if list.len() == 1 => {
+            number(list.into_iter().next().unwrap_or(GValue::Null), target)
+        }
+        _ => Err(GremlinError::Cast(format!(
+            "Cannot convert {:?} to {}",
+            value, target
+        ))),
+    }
+}
+
+macro_rules! impl_lenient {
+    ($t:ty, $n:ident => $convert:expr) => {
+        impl FromGValue for Lenient<$t> {
+            fn from_gvalue(value: GValue) -> GremlinResult<Self> {
+                let $n = number(value, stringify!($t))?;
+                $convert.map(Lenient)
+            }
+        }
+
+        impl TryFrom<GValue> for Lenient<$t> {
+            type Error = GremlinError;
+
+            fn try_from(value: GValue) -> GremlinResult<Self> {
+                FromGValue::from_gvalue(value)
+            }
+        }
+    };
+}
+
+macro_rules! impl_lenient_int {
+    ($t:ty) => {
+        impl_lenient!($t, n => {
+            let value = match n {
+                Number::Int(n) => i128::from(n),
+                // `as` saturates, out of range values are then rejected by `try_from`
+                Number::Float(n) if n.is_finite() => n.trunc() as i128,
+                Number::Float(n) => {
+                    return Err(GremlinError::Cast(format!(
+                        "{} is out of range for {}",
+                        n,
+                        stringify!($t)
+                    )))
+                }
+            };
+            <$t>::try_from(value).map_err(|_| {
+                GremlinError::Cast(format!("{} is out of range for {}", value, stringify!($t)))
+            })
+        });
+    };
+}
+
+macro_rules! impl_lenient_float {
+    ($t:ty) => {
+        impl_lenient!($t, n => GremlinResult::Ok(match n {
+            Number::Int(n) => n as $t,
+            Number::Float(n) => n as $t,
+        }));
+    };
+}
+
+impl_lenient_int!(i32);
+impl_lenient_int!(i64);
+impl_lenient_int!(u8);
+impl_lenient_int!(u16);
+impl_lenient_int!(u32);
+impl_lenient_int!(u64);
+impl_lenient_int!(usize);
+impl_lenient_float!(f32);
+impl_lenient_float!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::Lenient;
+    use crate::structure::{GValue, List};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn lenient_integers() {
+        assert_eq!(Lenient(3u32), GValue::Int64(3).take().unwrap());
+        assert_eq!(Lenient(3i32), GValue::Double(3.7).take().unwrap());
+        assert_eq!(
+            Lenient(-2i64),
+            Lenient::<i64>::try_from(GValue::Float(-2.5)).unwrap()
+        );
+        assert_eq!(
+            Lenient(7usize),
+            GValue::List(List::new(vec![GValue::Int32(7)]))
+                .take()
+                .unwrap()
+        );
+
+        assert!(GValue::Int64(300).take::<Lenient<u8>>().is_err());
+        assert!(GValue::Int32(-1).take::<Lenient<u64>>().is_err());
+        assert!(GValue::Double(f64::NAN).take::<Lenient<i64>>().is_err());
+        assert!(GValue::from("3").take::<Lenient<i64>>().is_err());
+    }
+
+    #[test]
+    fn lenient_floats() {
+        assert_eq!(Lenient(1.5f32), GValue::Double(1.5).take().unwrap());
+        assert_eq!(Lenient(4.0f64), GValue::Int64(4).take().unwrap());
+        assert_eq!(4.0, *GValue::Int32(4).take::<Lenient<f32>>().unwrap());
+    }
+}
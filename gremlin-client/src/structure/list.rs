@@ -1,4 +1,5 @@
 use crate::GValue;
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct List(Vec<GValue>);
@@ -46,3 +47,25 @@ impl std::ops::Index<usize> for List {
         self.0.get(key).expect("no entry found for key")
     }
 }
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_elements(f, self.iter())
+    }
+}
+
+// Writes `[a, b, c]`, as the Gremlin Console does for lists
+pub(crate) fn write_elements<I, D>(f: &mut fmt::Formatter, elements: I) -> fmt::Result
+where
+    I: IntoIterator<Item = D>,
+    D: fmt::Display,
+{
+    write!(f, "[")?;
+    for (i, element) in elements.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", element)?;
+    }
+    write!(f, "]")
+}
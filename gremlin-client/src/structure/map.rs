@@ -1,9 +1,13 @@
 use crate::error::GremlinError;
-use crate::structure::{Edge, GValue, Vertex};
+use crate::structure::list::write_elements;
+use crate::structure::value::{format_date, Date};
+use crate::structure::{Direction, Edge, GValue, Property, Vertex, VertexProperty, T};
 use crate::GremlinResult;
 use crate::Token;
 use std::collections::hash_map::IntoIter;
 use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Represent a Map<[GKey](struct.GKey),[GValue](struct.GValue)> which has ability to allow for non-String keys.
 /// TinkerPop type [here](http://tinkerpop.apache.org/docs/current/dev/io/#_map)
@@ -106,14 +110,125 @@ impl std::iter::FromIterator<(String, GValue)> for Map {
     }
 }
 
+/// Written as `[key:value, ...]` like the Gremlin Console does, entries sorted by key
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "[:]");
+        }
+        let mut entries: Vec<(String, &GValue)> =
+            self.0.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        write!(f, "[")?;
+        for (i, (key, value)) in entries.into_iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}:{}", key, value)?;
+        }
+        write!(f, "]")
+    }
+}
+
 /// Possible key types in a [Map](struct.Map)
+///
+/// Floating point keys are compared and hashed by their bit pattern.
+///
+/// Maps can't be keys: a [GValue](enum.GValue) has no `Hash` implementation, so neither has a
+/// [Map](struct.Map), and converting such a key fails with a cast error.
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+#[derive(Debug, Clone)]
 pub enum GKey {
     String(String),
     Token(Token),
     Vertex(Vertex),
     Edge(Edge),
+    T(T),
+    Direction(Direction),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    Uuid(uuid::Uuid),
+    Date(Date),
+    List(Vec<GKey>),
+    Property(Property),
+    VertexProperty(VertexProperty),
+}
+
+impl PartialEq for GKey {
+    fn eq(&self, other: &GKey) -> bool {
+        match (self, other) {
+            (GKey::String(a), GKey::String(b)) => a == b,
+            (GKey::Token(a), GKey::Token(b)) => a == b,
+            (GKey::Vertex(a), GKey::Vertex(b)) => a == b,
+            (GKey::Edge(a), GKey::Edge(b)) => a == b,
+            (GKey::T(a), GKey::T(b)) => a == b,
+            (GKey::Direction(a), GKey::Direction(b)) => a == b,
+            (GKey::Int32(a), GKey::Int32(b)) => a == b,
+            (GKey::Int64(a), GKey::Int64(b)) => a == b,
+            (GKey::Float(a), GKey::Float(b)) => a.to_bits() == b.to_bits(),
+            (GKey::Double(a), GKey::Double(b)) => a.to_bits() == b.to_bits(),
+            (GKey::Bool(a), GKey::Bool(b)) => a == b,
+            (GKey::Uuid(a), GKey::Uuid(b)) => a == b,
+            (GKey::Date(a), GKey::Date(b)) => a == b,
+            (GKey::List(a), GKey::List(b)) => a == b,
+            (GKey::Property(a), GKey::Property(b)) => a == b,
+            (GKey::VertexProperty(a), GKey::VertexProperty(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for GKey {}
+
+impl Hash for GKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            GKey::String(s) => s.hash(state),
+            GKey::Token(t) => t.hash(state),
+            GKey::Vertex(v) => v.hash(state),
+            GKey::Edge(e) => e.hash(state),
+            GKey::T(t) => t.hash(state),
+            GKey::Direction(d) => d.hash(state),
+            GKey::Int32(n) => n.hash(state),
+            GKey::Int64(n) => n.hash(state),
+            GKey::Float(n) => n.to_bits().hash(state),
+            GKey::Double(n) => n.to_bits().hash(state),
+            GKey::Bool(b) => b.hash(state),
+            GKey::Uuid(u) => u.hash(state),
+            GKey::Date(d) => d.hash(state),
+            GKey::List(l) => l.hash(state),
+            GKey::Property(p) => p.hash(state),
+            GKey::VertexProperty(p) => p.hash(state),
+        }
+    }
+}
+
+impl fmt::Display for GKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GKey::String(s) => write!(f, "{}", s),
+            GKey::Token(t) => write!(f, "{}", t),
+            GKey::Vertex(v) => write!(f, "{}", v),
+            GKey::Edge(e) => write!(f, "{}", e),
+            GKey::T(t) => write!(f, "{}", t),
+            GKey::Direction(d) => write!(f, "{}", d),
+            GKey::Int32(n) => write!(f, "{}", n),
+            GKey::Int64(n) => write!(f, "{}", n),
+            GKey::Float(n) => write!(f, "{:?}", n),
+            GKey::Double(n) => write!(f, "{:?}", n),
+            GKey::Bool(b) => write!(f, "{}", b),
+            GKey::Uuid(u) => write!(f, "{}", u),
+            GKey::Date(d) => write!(f, "{}", format_date(d)),
+            GKey::List(keys) => write_elements(f, keys),
+            GKey::Property(p) => write!(f, "{}", p),
+            GKey::VertexProperty(p) => write!(f, "{}", p),
+        }
+    }
 }
 
 impl From<&str> for GKey {
@@ -128,6 +243,18 @@ impl From<String> for GKey {
     }
 }
 
+impl From<T> for GKey {
+    fn from(val: T) -> Self {
+        GKey::T(val)
+    }
+}
+
+impl From<Direction> for GKey {
+    fn from(val: Direction) -> Self {
+        GKey::Direction(val)
+    }
+}
+
 impl From<&Vertex> for GKey {
     fn from(val: &Vertex) -> Self {
         GKey::Vertex(val.clone())
@@ -139,3 +266,156 @@ impl From<&Edge> for GKey {
         GKey::Edge(val.clone())
     }
 }
+
+impl From<i32> for GKey {
+    fn from(val: i32) -> Self {
+        GKey::Int32(val)
+    }
+}
+
+impl From<i64> for GKey {
+    fn from(val: i64) -> Self {
+        GKey::Int64(val)
+    }
+}
+
+impl From<f32> for GKey {
+    fn from(val: f32) -> Self {
+        GKey::Float(val)
+    }
+}
+
+impl From<f64> for GKey {
+    fn from(val: f64) -> Self {
+        GKey::Double(val)
+    }
+}
+
+impl From<bool> for GKey {
+    fn from(val: bool) -> Self {
+        GKey::Bool(val)
+    }
+}
+
+impl From<uuid::Uuid> for GKey {
+    fn from(val: uuid::Uuid) -> Self {
+        GKey::Uuid(val)
+    }
+}
+
+impl From<Date> for GKey {
+    fn from(val: Date) -> Self {
+        GKey::Date(val)
+    }
+}
+
+impl From<Vec<GKey>> for GKey {
+    fn from(val: Vec<GKey>) -> Self {
+        GKey::List(val)
+    }
+}
+
+impl From<Property> for GKey {
+    fn from(val: Property) -> Self {
+        GKey::Property(val)
+    }
+}
+
+impl From<VertexProperty> for GKey {
+    fn from(val: VertexProperty) -> Self {
+        GKey::VertexProperty(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GKey, Map};
+    use crate::conversion::FromGValue;
+    use crate::structure::{GValue, Property, VertexProperty, GID, T};
+    use chrono::offset::TimeZone;
+    use chrono::Utc;
+    use std::collections::HashMap;
+
+    fn keys() -> Vec<GKey> {
+        vec![
+            GKey::from("name"),
+            GKey::from(T::Id),
+            GKey::from(29),
+            GKey::from(2i64),
+            GKey::from(1.5f32),
+            GKey::from(0.4),
+            GKey::from(true),
+            GKey::from(uuid::Uuid::parse_str("41d2e28a-20a4-4ab0-b379-d810dede3786").unwrap()),
+            GKey::from(Utc.timestamp_millis(1481750076295)),
+            GKey::from(vec![GKey::from("a"), GKey::from(1)]),
+            GKey::from(Property::new("since", 2009)),
+            GKey::from(VertexProperty::new(GID::Int64(1), "name", "marko")),
+        ]
+    }
+
+    #[test]
+    fn key_gvalue_round_trip() {
+        for key in keys() {
+            let value = GValue::from(key.clone());
+
+            assert_eq!(key, GKey::from_gvalue(value).unwrap());
+        }
+    }
+
+    #[test]
+    fn lookup_by_key() {
+        let map: Map = keys()
+            .into_iter()
+            .enumerate()
+            .map(|(i, k)| (k, GValue::from(i as i32)))
+            .collect::<HashMap<_, _>>()
+            .into();
+
+        assert_eq!(12, map.len());
+        for (i, key) in keys().into_iter().enumerate() {
+            assert_eq!(Some(&GValue::from(i as i32)), map.get(key));
+        }
+        assert_eq!(None, map.get(1.5f64));
+        assert_eq!(None, map.get(29i64));
+    }
+
+    #[test]
+    fn float_keys_use_bit_pattern() {
+        let mut map = HashMap::new();
+        map.insert(GKey::from(f64::NAN), GValue::from(1));
+        let map = Map::from(map);
+
+        assert_eq!(Some(&GValue::from(1)), map.get(f64::NAN));
+        assert_ne!(GKey::from(0.0), GKey::from(-0.0));
+    }
+
+    #[test]
+    fn property_keys_with_nan() {
+        let mut map = HashMap::new();
+        map.insert(
+            GKey::from(Property::new("weight", f64::NAN)),
+            GValue::from(1),
+        );
+        map.insert(
+            GKey::from(VertexProperty::new(GID::Int64(1), "weight", f32::NAN)),
+            GValue::from(2),
+        );
+        let map = Map::from(map);
+
+        assert_eq!(
+            Some(&GValue::from(1)),
+            map.get(Property::new("weight", f64::NAN))
+        );
+        assert_eq!(
+            Some(&GValue::from(2)),
+            map.get(VertexProperty::new(GID::Int64(1), "weight", f32::NAN))
+        );
+    }
+
+    #[test]
+    fn map_keys_are_rejected() {
+        let value = GValue::from(Map::from(HashMap::<String, GValue>::new()));
+
+        assert!(GKey::from_gvalue(value).is_err());
+    }
+}
@@ -1,3 +1,6 @@
+use crate::structure::{GKey, GValue, Map};
+use std::fmt;
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct TraversalExplanation {
     final_t: Vec<String>,
@@ -32,6 +35,18 @@ impl IntermediateRepr {
             category,
         }
     }
+
+    pub fn traversal(&self) -> &Vec<String> {
+        &self.traversal
+    }
+
+    pub fn strategy(&self) -> &String {
+        &self.strategy
+    }
+
+    pub fn category(&self) -> &String {
+        &self.category
+    }
 }
 impl TraversalExplanation {
     pub fn new(
@@ -78,6 +93,7 @@ pub struct Metric {
     traversers: i64,
     perc_duration: f64,
     nested: Vec<Metric>,
+    annotations: Map,
 }
 
 impl Metric {
@@ -100,6 +116,20 @@ impl Metric {
     pub fn traversers(&self) -> &i64 {
         &self.traversers
     }
+
+    pub fn nested(&self) -> &Vec<Metric> {
+        &self.nested
+    }
+
+    /// Step annotations other than `percentDur`, e.g. the index used by a step
+    pub fn annotations(&self) -> &Map {
+        &self.annotations
+    }
+
+    pub fn with_annotations(mut self, annotations: Map) -> Self {
+        self.annotations = annotations;
+        self
+    }
 }
 
 impl Metric {
@@ -124,6 +154,155 @@ impl Metric {
             traversers,
             perc_duration,
             nested,
+            annotations: Map::empty(),
+        }
+    }
+}
+
+const STEP_WIDTH: usize = 60;
+
+impl fmt::Display for TraversalMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Traversal Metrics")?;
+        writeln!(
+            f,
+            "{:<width$}{:>10}{:>12}{:>16}{:>9}",
+            "Step",
+            "Count",
+            "Traversers",
+            "Time (ms)",
+            "% Dur",
+            width = STEP_WIDTH
+        )?;
+        writeln!(f, "{}", "=".repeat(STEP_WIDTH + 47))?;
+        for metric in &self.metrics {
+            metric.render(f, 0)?;
+        }
+        write!(
+            f,
+            "{:<width$}{:>10}{:>12}{:>16.3}{:>9}",
+            ">TOTAL",
+            "-",
+            "-",
+            self.duration,
+            "-",
+            width = STEP_WIDTH
+        )
+    }
+}
+
+/// Written like the Gremlin Console `explain()` output
+impl fmt::Display for TraversalExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .intermediate
+            .iter()
+            .map(|i| i.strategy.len() + 4)
+            .chain(std::iter::once("Original Traversal".len()))
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        writeln!(f, "Traversal Explanation")?;
+        writeln!(f, "{}", "=".repeat(width + 40))?;
+        writeln!(
+            f,
+            "{:<width$}[{}]",
+            "Original Traversal",
+            self.original.join(", "),
+            width = width
+        )?;
+        writeln!(f)?;
+        for intermediate in &self.intermediate {
+            writeln!(f, "{:<width$}", intermediate, width = width)?;
         }
+        writeln!(f)?;
+        write!(
+            f,
+            "{:<width$}[{}]",
+            "Final Traversal",
+            self.final_t.join(", "),
+            width = width
+        )
+    }
+}
+
+impl fmt::Display for IntermediateRepr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let category = self
+            .category
+            .chars()
+            .next()
+            .map(|c| c.to_ascii_uppercase())
+            .unwrap_or('?');
+        let strategy = format!("{} [{}]", self.strategy, category);
+        let width = f.width().unwrap_or(0);
+        write!(
+            f,
+            "{:<width$}[{}]",
+            strategy,
+            self.traversal.join(", "),
+            width = width
+        )
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.render(f, 0)
+    }
+}
+
+impl Metric {
+    // One line per step, followed by its annotations and nested metrics indented
+    // by one level.
+    fn render(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+
+        writeln!(
+            f,
+            "{:<width$}{:>10}{:>12}{:>16.3}{:>9.2}",
+            format!("{}{}", indent, self.name),
+            self.count,
+            self.traversers,
+            self.duration,
+            self.perc_duration,
+            width = STEP_WIDTH
+        )?;
+
+        let mut annotations: Vec<(String, String)> = self
+            .annotations
+            .iter()
+            .map(|(k, v)| (annotation_key(k), annotation_value(v)))
+            .collect();
+        annotations.sort();
+
+        for (key, value) in annotations {
+            writeln!(f, "{}  \\_{}={}", indent, key, value)?;
+        }
+
+        for nested in &self.nested {
+            nested.render(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+fn annotation_key(key: &GKey) -> String {
+    match key {
+        GKey::String(s) => s.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn annotation_value(value: &GValue) -> String {
+    match value {
+        GValue::String(s) => s.clone(),
+        GValue::Int32(v) => v.to_string(),
+        GValue::Int64(v) => v.to_string(),
+        GValue::Float(v) => v.to_string(),
+        GValue::Double(v) => v.to_string(),
+        GValue::Bool(v) => v.to_string(),
+        other => format!("{:?}", other),
     }
 }
@@ -1,8 +1,15 @@
+mod binding;
 mod cardinality;
+mod column;
+mod custom;
+mod direction;
 mod edge;
 mod either;
 mod gid;
+mod json;
 mod label;
+mod lambda;
+mod lenient;
 mod list;
 mod macros;
 mod map;
@@ -17,23 +24,30 @@ mod t;
 mod text_p;
 mod token;
 mod traverser;
+mod tree;
 mod value;
 mod vertex;
 mod vertex_property;
 
-pub use self::edge::Edge;
+pub use self::binding::Binding;
+pub use self::custom::Custom;
+pub use self::edge::{Edge, EdgeBuilder};
 pub use self::gid::{GIDs, GID};
+pub use self::lambda::Lambda;
+pub use self::lenient::Lenient;
 pub use self::list::List;
 pub use self::metrics::{IntermediateRepr, Metric, TraversalExplanation, TraversalMetrics};
-pub use self::path::Path;
+pub use self::path::{Path, PathBuilder};
 pub use self::property::Property;
 pub use self::result::GResultSet;
 pub use self::set::Set;
 pub use self::token::Token;
 pub use self::value::GValue;
-pub use self::vertex::Vertex;
+pub use self::vertex::{Vertex, VertexBuilder};
 pub use self::vertex_property::{GProperty, VertexProperty};
 pub use cardinality::Cardinality;
+pub use column::Column;
+pub use direction::Direction;
 pub use either::*;
 pub use label::Labels;
 pub use map::{GKey, Map};
@@ -42,3 +56,4 @@ pub use pop::Pop;
 pub use t::T;
 pub use text_p::TextP;
 pub use traverser::Traverser;
+pub use tree::{Branch, DepthFirst, Tree};
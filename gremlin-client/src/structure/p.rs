@@ -1,6 +1,7 @@
 use crate::structure::either::Either2;
 use crate::structure::text_p::TextP;
 use crate::{GValue, ToGValue};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct P {
@@ -76,6 +77,12 @@ impl P {
     }
 }
 
+impl fmt::Display for P {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.operator, self.value)
+    }
+}
+
 pub trait IntoPredicate {
     fn into_predicate(self) -> Either2<P, TextP>;
 }
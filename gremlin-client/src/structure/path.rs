@@ -1,4 +1,6 @@
-use crate::structure::{GValue, List};
+use crate::structure::list::write_elements;
+use crate::structure::{GValue, List, Pop};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Path {
@@ -14,7 +16,145 @@ impl Path {
         }
     }
 
+    pub fn builder() -> PathBuilder {
+        PathBuilder {
+            labels: vec![],
+            objects: vec![],
+        }
+    }
+
     pub fn objects(&self) -> &List {
         &self.objects
     }
+
+    /// The step labels of every object in the path, in the same order as [objects](#method.objects)
+    pub fn labels(&self) -> Vec<Vec<&str>> {
+        let labels: Box<dyn Iterator<Item = &GValue>> = match &*self.labels {
+            GValue::List(list) => Box::new(list.iter()),
+            GValue::Set(set) => Box::new(set.iter()),
+            _ => Box::new(std::iter::empty()),
+        };
+
+        labels.map(labels_of).collect()
+    }
+
+    /// The objects labeled with `label`: a single object if only one matches,
+    /// a list otherwise, as with `Pop::Mixed`
+    pub fn get<L>(&self, label: L) -> Option<GValue>
+    where
+        L: AsRef<str>,
+    {
+        self.get_with_pop(Pop::Mixed, label)
+    }
+
+    pub fn get_with_pop<L>(&self, pop: Pop, label: L) -> Option<GValue>
+    where
+        L: AsRef<str>,
+    {
+        let mut matches: Vec<GValue> = self
+            .labels()
+            .into_iter()
+            .zip(self.objects.iter())
+            .filter(|(labels, _)| labels.contains(&label.as_ref()))
+            .map(|(_, object)| object.clone())
+            .collect();
+
+        match pop {
+            Pop::All => Some(GValue::List(List::new(matches))),
+            Pop::First if matches.is_empty() => None,
+            Pop::First => Some(matches.swap_remove(0)),
+            Pop::Last => matches.pop(),
+            Pop::Mixed if matches.len() > 1 => Some(GValue::List(List::new(matches))),
+            Pop::Mixed => matches.pop(),
+        }
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "path")?;
+        write_elements(f, self.objects.iter())
+    }
+}
+
+fn labels_of(value: &GValue) -> Vec<&str> {
+    let labels: Box<dyn Iterator<Item = &GValue>> = match value {
+        GValue::List(list) => Box::new(list.iter()),
+        GValue::Set(set) => Box::new(set.iter()),
+        _ => Box::new(std::iter::empty()),
+    };
+
+    labels
+        .filter_map(|label| match label {
+            GValue::String(s) => Some(s.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub struct PathBuilder {
+    labels: Vec<GValue>,
+    objects: Vec<GValue>,
+}
+
+impl PathBuilder {
+    pub fn step<L, S, O>(mut self, labels: L, object: O) -> Self
+    where
+        L: IntoIterator<Item = S>,
+        S: Into<String>,
+        O: Into<GValue>,
+    {
+        let labels: Vec<GValue> = labels
+            .into_iter()
+            .map(|label| GValue::String(label.into()))
+            .collect();
+
+        self.labels.push(GValue::Set(labels.into()));
+        self.objects.push(object.into());
+        self
+    }
+
+    pub fn build(self) -> Path {
+        Path::new(
+            GValue::List(List::new(self.labels)),
+            List::new(self.objects),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Path;
+    use crate::structure::{GValue, List, Pop};
+
+    #[test]
+    fn get_by_label_test() {
+        let path = Path::builder()
+            .step(vec!["a"], "marko")
+            .step(Vec::<String>::new(), "josh")
+            .step(vec!["a", "b"], "ripple")
+            .build();
+
+        assert_eq!(vec![vec!["a"], vec![], vec!["a", "b"]], path.labels());
+
+        let both = GValue::List(List::new(vec!["marko".into(), "ripple".into()]));
+
+        assert_eq!(Some(both.clone()), path.get("a"));
+        assert_eq!(Some(GValue::from("ripple")), path.get("b"));
+        assert_eq!(None, path.get("c"));
+
+        assert_eq!(Some(both), path.get_with_pop(Pop::All, "a"));
+        assert_eq!(
+            Some(GValue::from("marko")),
+            path.get_with_pop(Pop::First, "a")
+        );
+        assert_eq!(
+            Some(GValue::from("ripple")),
+            path.get_with_pop(Pop::Last, "a")
+        );
+        assert_eq!(
+            Some(GValue::List(List::new(vec![]))),
+            path.get_with_pop(Pop::All, "c")
+        );
+    }
 }
@@ -1,8 +1,9 @@
 use crate::conversion::{BorrowFromGValue, FromGValue};
 use crate::GValue;
 use crate::GremlinResult;
+use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct Property {
     label: String,
     value: Box<GValue>,
@@ -24,6 +25,17 @@ impl Property {
         &self.value
     }
 
+    pub fn value_mut(&mut self) -> &mut GValue {
+        &mut self.value
+    }
+
+    pub fn set_value<GT>(&mut self, value: GT)
+    where
+        GT: Into<GValue>,
+    {
+        *self.value = value.into();
+    }
+
     pub fn take<T>(self) -> GremlinResult<T>
     where
         T: FromGValue,
@@ -42,3 +54,33 @@ impl Property {
         &self.label
     }
 }
+
+// Floating point values are compared by their bit pattern like in GKey, so a property holding
+// a `NaN` is equal to itself
+pub(crate) fn same_value(a: &GValue, b: &GValue) -> bool {
+    match (a, b) {
+        (GValue::Float(a), GValue::Float(b)) => a.to_bits() == b.to_bits(),
+        (GValue::Double(a), GValue::Double(b)) => a.to_bits() == b.to_bits(),
+        _ => a == b,
+    }
+}
+
+impl PartialEq for Property {
+    fn eq(&self, other: &Property) -> bool {
+        self.label == other.label && same_value(&self.value, &other.value)
+    }
+}
+
+impl std::cmp::Eq for Property {}
+
+impl std::hash::Hash for Property {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "p[{}->{}]", self.label, self.value)
+    }
+}
@@ -38,6 +38,16 @@ impl GResultSet {
     }
 }
 
+impl Drop for GResultSet {
+    fn drop(&mut self) {
+        // Frames of a partially consumed response are still on the wire, so the
+        // connection cannot be reused for another request.
+        if self.response.status.code == 206 {
+            self.conn.set_broken();
+        }
+    }
+}
+
 impl Iterator for GResultSet {
     type Item = GremlinResult<GValue>;
 
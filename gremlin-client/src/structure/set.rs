@@ -1,4 +1,6 @@
+use crate::structure::list::write_elements;
 use crate::GValue;
+use std::fmt;
 use std::vec::IntoIter;
 
 // pub type Set = Vec<GValue>;
@@ -29,3 +31,9 @@ impl IntoIterator for Set {
         self.0.into_iter()
     }
 }
+
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_elements(f, self.iter())
+    }
+}
@@ -1,7 +1,20 @@
-#[derive(Debug, PartialEq, Clone)]
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum T {
     Id,
     Key,
     Label,
     Value,
 }
+
+impl Display for T {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            T::Id => write!(f, "id"),
+            T::Key => write!(f, "key"),
+            T::Label => write!(f, "label"),
+            T::Value => write!(f, "value"),
+        }
+    }
+}
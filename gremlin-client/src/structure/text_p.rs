@@ -1,4 +1,5 @@
 use crate::{GValue, ToGValue};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct TextP {
@@ -66,3 +67,9 @@ impl TextP {
         TextP::new("notContaining", value.to_gvalue())
     }
 }
+
+impl fmt::Display for TextP {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({})", self.operator, self.value)
+    }
+}
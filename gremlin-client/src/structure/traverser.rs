@@ -1,5 +1,6 @@
 use crate::conversion::FromGValue;
 use crate::{GValue, GremlinResult};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Traverser {
@@ -15,6 +16,27 @@ impl Traverser {
         }
     }
 
+    pub fn bulk(&self) -> i64 {
+        self.bulk
+    }
+
+    pub fn get(&self) -> &GValue {
+        &self.value
+    }
+
+    /// Split off one copy of the value, returning it together with the traverser
+    /// holding the remaining bulk, if any.
+    pub(crate) fn split(self) -> Option<(GValue, Option<Traverser>)> {
+        match self.bulk {
+            b if b <= 0 => None,
+            1 => Some((*self.value, None)),
+            b => Some((
+                (*self.value).clone(),
+                Some(Traverser::new(b - 1, *self.value)),
+            )),
+        }
+    }
+
     pub fn take<T>(self) -> GremlinResult<T>
     where
         T: FromGValue,
@@ -22,3 +44,29 @@ impl Traverser {
         T::from_gvalue(*self.value)
     }
 }
+
+impl fmt::Display for Traverser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Traverser;
+    use crate::GValue;
+
+    #[test]
+    fn split_expands_bulk() {
+        let mut current = Some(Traverser::new(3, GValue::from("a")));
+        let mut values = vec![];
+
+        while let Some((value, rest)) = current.and_then(Traverser::split) {
+            values.push(value.take::<String>().unwrap());
+            current = rest;
+        }
+
+        assert_eq!(vec!["a", "a", "a"], values);
+        assert_eq!(None, Traverser::new(0, GValue::from("a")).split());
+    }
+}
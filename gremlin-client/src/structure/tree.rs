@@ -0,0 +1,253 @@
+use crate::conversion::FromGValue;
+use crate::structure::{GKey, GValue, Map};
+use crate::GremlinResult;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The result of a `tree()` step, where every branch maps an object to the subtree of
+/// the objects that followed it in the traversal.
+/// TinkerPop type [here](http://tinkerpop.apache.org/docs/current/dev/io/#_tree)
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Tree {
+    branches: Vec<Branch>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Branch {
+    key: GValue,
+    tree: Tree,
+}
+
+impl Tree {
+    pub fn new(branches: Vec<Branch>) -> Tree {
+        Tree { branches }
+    }
+
+    pub fn branches(&self) -> &[Branch] {
+        &self.branches
+    }
+
+    /// The objects at the root of the tree
+    pub fn children(&self) -> impl Iterator<Item = &GValue> {
+        self.branches.iter().map(Branch::key)
+    }
+
+    /// The subtree below the root object equal to `key`
+    pub fn get<K>(&self, key: K) -> Option<&Tree>
+    where
+        K: Into<GValue>,
+    {
+        let key = key.into();
+        self.branches
+            .iter()
+            .find(|branch| branch.key == key)
+            .map(Branch::tree)
+    }
+
+    /// Pre-order iteration of every object with its depth, the root objects having depth 0
+    pub fn depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst {
+            stack: self
+                .branches
+                .iter()
+                .rev()
+                .map(|branch| (0, branch))
+                .collect(),
+        }
+    }
+
+    /// The objects without children
+    pub fn leaves(&self) -> Vec<&GValue> {
+        self.depth_first()
+            .filter(|(_, branch)| branch.tree.is_empty())
+            .map(|(_, branch)| branch.key())
+            .collect()
+    }
+
+    /// Converts the tree into nested maps, every object keying the map of its children
+    pub fn to_map(&self) -> GremlinResult<Map> {
+        self.branches
+            .iter()
+            .map(|branch| {
+                Ok((
+                    GKey::from_gvalue(branch.key.clone())?,
+                    GValue::Map(branch.tree.to_map()?),
+                ))
+            })
+            .collect::<GremlinResult<HashMap<GKey, GValue>>>()
+            .map(Map::from)
+    }
+
+    pub fn len(&self) -> usize {
+        self.branches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+}
+
+impl Branch {
+    pub fn new<K>(key: K, tree: Tree) -> Branch
+    where
+        K: Into<GValue>,
+    {
+        Branch {
+            key: key.into(),
+            tree,
+        }
+    }
+
+    pub fn key(&self) -> &GValue {
+        &self.key
+    }
+
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// The objects directly below this one
+    pub fn children(&self) -> impl Iterator<Item = &GValue> {
+        self.tree.children()
+    }
+}
+
+impl IntoIterator for Tree {
+    type Item = Branch;
+    type IntoIter = std::vec::IntoIter<Branch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.branches.into_iter()
+    }
+}
+
+/// Written as nested `{key={...}}` maps, as the Gremlin Console does
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, branch) in self.branches.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", branch)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Display for Branch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.tree)
+    }
+}
+
+/// Iterator returned by [Tree::depth_first](struct.Tree.html#method.depth_first)
+pub struct DepthFirst<'a> {
+    stack: Vec<(usize, &'a Branch)>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (usize, &'a Branch);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, branch) = self.stack.pop()?;
+        self.stack.extend(
+            branch
+                .tree
+                .branches
+                .iter()
+                .rev()
+                .map(|child| (depth + 1, child)),
+        );
+        Some((depth, branch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Branch, Tree};
+    use crate::structure::{GKey, GValue};
+
+    // marko -> (vadas, josh -> (ripple, lop))
+    fn tree() -> Tree {
+        Tree::new(vec![Branch::new(
+            "marko",
+            Tree::new(vec![
+                Branch::new("vadas", Tree::default()),
+                Branch::new(
+                    "josh",
+                    Tree::new(vec![
+                        Branch::new("ripple", Tree::default()),
+                        Branch::new("lop", Tree::default()),
+                    ]),
+                ),
+            ]),
+        )])
+    }
+
+    #[test]
+    fn navigation_test() {
+        let tree = tree();
+
+        assert_eq!(
+            vec![&GValue::from("marko")],
+            tree.children().collect::<Vec<_>>()
+        );
+
+        let marko = tree.get("marko").unwrap();
+        assert_eq!(2, marko.len());
+        assert_eq!(
+            vec![&GValue::from("ripple"), &GValue::from("lop")],
+            marko.get("josh").unwrap().children().collect::<Vec<_>>()
+        );
+        assert!(marko.get("lop").is_none());
+        assert_eq!(
+            vec![&GValue::from("vadas"), &GValue::from("josh")],
+            tree.branches()[0].children().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn depth_first_and_leaves_test() {
+        let tree = tree();
+
+        let visited = tree
+            .depth_first()
+            .map(|(depth, branch)| (depth, branch.key().clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (0, GValue::from("marko")),
+                (1, GValue::from("vadas")),
+                (1, GValue::from("josh")),
+                (2, GValue::from("ripple")),
+                (2, GValue::from("lop")),
+            ],
+            visited
+        );
+
+        assert_eq!(
+            vec![
+                &GValue::from("vadas"),
+                &GValue::from("ripple"),
+                &GValue::from("lop")
+            ],
+            tree.leaves()
+        );
+    }
+
+    #[test]
+    fn to_map_test() {
+        let map = tree().to_map().unwrap();
+
+        let marko = map["marko"].get::<crate::structure::Map>().unwrap();
+        let josh = marko["josh"].get::<crate::structure::Map>().unwrap();
+
+        assert_eq!(2, marko.len());
+        assert!(josh[GKey::from("lop")]
+            .get::<crate::structure::Map>()
+            .unwrap()
+            .is_empty());
+    }
+}
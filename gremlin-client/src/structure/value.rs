@@ -1,15 +1,17 @@
 use crate::conversion::{BorrowFromGValue, FromGValue};
-use crate::process::traversal::{Bytecode, Order, Scope};
+use crate::process::traversal::{Barrier, Bytecode, Merge, Operator, Order, Pick, Scope, DT};
 use crate::structure::traverser::Traverser;
 use crate::structure::{
-    label::LabelType, Cardinality, Edge, GKey, IntermediateRepr, List, Map, Metric, Path, Property,
-    Set, Token, TraversalExplanation, TraversalMetrics, Vertex, VertexProperty,
+    label::LabelType, Binding, Cardinality, Column, Custom, Direction, Edge, GKey,
+    IntermediateRepr, Lambda, List, Map, Metric, Path, Property, Set, Token, TraversalExplanation,
+    TraversalMetrics, Tree, Vertex, VertexProperty,
 };
 use crate::structure::{Pop, TextP, P, T};
 use crate::{GremlinError, GremlinResult};
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 pub type Date = chrono::DateTime<chrono::offset::Utc>;
 use std::convert::TryInto;
+use std::fmt;
 use std::hash::Hash;
 /// Represent possible values coming from the [Gremlin Server](http://tinkerpop.apache.org/docs/3.4.0/dev/io/)
 #[allow(clippy::large_enum_variant)]
@@ -46,6 +48,17 @@ pub enum GValue {
     TextP(TextP),
     Pop(Pop),
     Cardinality(Cardinality),
+    Lambda(Lambda),
+    Direction(Direction),
+    Merge(Merge),
+    DT(DT),
+    Column(Column),
+    Operator(Operator),
+    Barrier(Barrier),
+    Pick(Pick),
+    Binding(Binding),
+    Custom(Custom),
+    Tree(Tree),
 }
 
 impl GValue {
@@ -64,6 +77,60 @@ impl GValue {
     }
 }
 
+/// Written like the Gremlin Console does, e.g. `v[1]`, `e[7][1-knows->2]` or `[name:marko]`
+impl fmt::Display for GValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GValue::Null => write!(f, "null"),
+            GValue::Vertex(v) => write!(f, "{}", v),
+            GValue::Edge(e) => write!(f, "{}", e),
+            GValue::VertexProperty(p) => write!(f, "{}", p),
+            GValue::Property(p) => write!(f, "{}", p),
+            GValue::Uuid(u) => write!(f, "{}", u),
+            GValue::Int32(n) => write!(f, "{}", n),
+            GValue::Int64(n) => write!(f, "{}", n),
+            GValue::Float(n) => write!(f, "{:?}", n),
+            GValue::Double(n) => write!(f, "{:?}", n),
+            GValue::Date(d) => write!(f, "{}", format_date(d)),
+            GValue::List(l) => write!(f, "{}", l),
+            GValue::Set(s) => write!(f, "{}", s),
+            GValue::Map(m) => write!(f, "{}", m),
+            GValue::Token(t) => write!(f, "{}", t),
+            GValue::String(s) => write!(f, "{}", s),
+            GValue::Path(p) => write!(f, "{}", p),
+            GValue::TraversalMetrics(m) => write!(f, "{}", m),
+            GValue::Metric(m) => write!(f, "{}", m),
+            GValue::TraversalExplanation(e) => write!(f, "{}", e),
+            GValue::IntermediateRepr(i) => write!(f, "{}", i),
+            GValue::P(p) => write!(f, "{}", p),
+            GValue::T(t) => write!(f, "{}", t),
+            GValue::Bytecode(code) => write!(f, "{}", code),
+            GValue::Traverser(t) => write!(f, "{}", t),
+            GValue::Scope(s) => write!(f, "{}", s),
+            GValue::Order(o) => write!(f, "{}", o),
+            GValue::Bool(b) => write!(f, "{}", b),
+            GValue::TextP(p) => write!(f, "{}", p),
+            GValue::Pop(p) => write!(f, "{}", p),
+            GValue::Cardinality(c) => write!(f, "{}", c),
+            GValue::Lambda(l) => write!(f, "{}", l),
+            GValue::Direction(d) => write!(f, "{}", d),
+            GValue::Merge(m) => write!(f, "{}", m),
+            GValue::DT(dt) => write!(f, "{}", dt),
+            GValue::Column(c) => write!(f, "{}", c),
+            GValue::Operator(o) => write!(f, "{}", o),
+            GValue::Barrier(b) => write!(f, "{}", b),
+            GValue::Pick(p) => write!(f, "{}", p),
+            GValue::Binding(b) => write!(f, "{}", b),
+            GValue::Custom(c) => write!(f, "{}", c),
+            GValue::Tree(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+pub(crate) fn format_date(date: &Date) -> String {
+    date.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
 impl From<Date> for GValue {
     fn from(val: Date) -> Self {
         GValue::Date(val)
@@ -204,6 +271,12 @@ impl From<BTreeMap<String, GValue>> for GValue {
     }
 }
 
+impl From<Map> for GValue {
+    fn from(val: Map) -> Self {
+        GValue::Map(val)
+    }
+}
+
 impl From<Vec<GValue>> for GValue {
     fn from(val: Vec<GValue>) -> Self {
         GValue::List(List::new(val))
@@ -233,6 +306,20 @@ impl From<GKey> for GValue {
             GKey::Token(s) => GValue::String(s.value().clone()),
             GKey::Vertex(v) => GValue::Vertex(v),
             GKey::Edge(v) => GValue::Edge(v),
+            GKey::T(t) => GValue::T(t),
+            GKey::Direction(d) => GValue::Direction(d),
+            GKey::Int32(n) => GValue::Int32(n),
+            GKey::Int64(n) => GValue::Int64(n),
+            GKey::Float(n) => GValue::Float(n),
+            GKey::Double(n) => GValue::Double(n),
+            GKey::Bool(b) => GValue::Bool(b),
+            GKey::Uuid(u) => GValue::Uuid(u),
+            GKey::Date(d) => GValue::Date(d),
+            GKey::List(l) => {
+                GValue::List(l.into_iter().map(GValue::from).collect::<Vec<_>>().into())
+            }
+            GKey::Property(p) => GValue::Property(p),
+            GKey::VertexProperty(p) => GValue::VertexProperty(p),
         }
     }
 }
@@ -277,12 +364,84 @@ impl From<LabelType> for GValue {
     }
 }
 
+impl From<Pop> for GValue {
+    fn from(val: Pop) -> GValue {
+        GValue::Pop(val)
+    }
+}
+
 impl From<Cardinality> for GValue {
     fn from(val: Cardinality) -> GValue {
         GValue::Cardinality(val)
     }
 }
 
+impl From<Direction> for GValue {
+    fn from(val: Direction) -> GValue {
+        GValue::Direction(val)
+    }
+}
+
+impl From<Merge> for GValue {
+    fn from(val: Merge) -> GValue {
+        GValue::Merge(val)
+    }
+}
+
+impl From<DT> for GValue {
+    fn from(val: DT) -> GValue {
+        GValue::DT(val)
+    }
+}
+
+impl From<Column> for GValue {
+    fn from(val: Column) -> GValue {
+        GValue::Column(val)
+    }
+}
+
+impl From<Operator> for GValue {
+    fn from(val: Operator) -> GValue {
+        GValue::Operator(val)
+    }
+}
+
+impl From<Barrier> for GValue {
+    fn from(val: Barrier) -> GValue {
+        GValue::Barrier(val)
+    }
+}
+
+impl From<Pick> for GValue {
+    fn from(val: Pick) -> GValue {
+        GValue::Pick(val)
+    }
+}
+
+impl From<Tree> for GValue {
+    fn from(val: Tree) -> GValue {
+        GValue::Tree(val)
+    }
+}
+
+impl From<Custom> for GValue {
+    fn from(val: Custom) -> GValue {
+        GValue::Custom(val)
+    }
+}
+
+impl From<Binding> for GValue {
+    fn from(val: Binding) -> GValue {
+        GValue::Binding(val)
+    }
+}
+
+impl From<Lambda> for GValue {
+    fn from(val: Lambda) -> GValue {
+        GValue::Lambda(val)
+    }
+}
+
 impl From<uuid::Uuid> for GValue {
     fn from(val: uuid::Uuid) -> GValue {
         GValue::Uuid(val)
@@ -324,16 +483,33 @@ impl std::convert::TryFrom<GValue> for i64 {
 
     fn try_from(value: GValue) -> GremlinResult<Self> {
         match value {
-            GValue::Int64(s) => Ok(s),
             GValue::List(s) => from_list(s),
-            _ => Err(GremlinError::Cast(format!(
-                "Cannot cast {:?} to i32",
-                value
-            ))),
+            value => FromGValue::from_gvalue(value),
         }
     }
 }
 
+macro_rules! impl_try_from_unsigned {
+    ($t:ty) => {
+        impl std::convert::TryFrom<GValue> for $t {
+            type Error = crate::GremlinError;
+
+            fn try_from(value: GValue) -> GremlinResult<Self> {
+                match value {
+                    GValue::List(s) => from_list(s),
+                    value => FromGValue::from_gvalue(value),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_unsigned!(u8);
+impl_try_from_unsigned!(u16);
+impl_try_from_unsigned!(u32);
+impl_try_from_unsigned!(u64);
+impl_try_from_unsigned!(usize);
+
 impl std::convert::TryFrom<GValue> for uuid::Uuid {
     type Error = crate::GremlinError;
 
@@ -399,12 +575,8 @@ impl std::convert::TryFrom<GValue> for f64 {
 
     fn try_from(value: GValue) -> GremlinResult<Self> {
         match value {
-            GValue::Double(x) => Ok(x),
             GValue::List(s) => from_list(s),
-            _ => Err(GremlinError::Cast(format!(
-                "Cannot cast {:?} to f64",
-                value
-            ))),
+            value => FromGValue::from_gvalue(value),
         }
     }
 }
@@ -449,6 +621,11 @@ impl_try_from_option!(f64);
 impl_try_from_option!(Date);
 impl_try_from_option!(uuid::Uuid);
 impl_try_from_option!(bool);
+impl_try_from_option!(u8);
+impl_try_from_option!(u16);
+impl_try_from_option!(u32);
+impl_try_from_option!(u64);
+impl_try_from_option!(usize);
 
 fn for_list<T>(glist: &List) -> GremlinResult<Vec<T>>
 where
@@ -481,22 +658,6 @@ where
 
 macro_rules! impl_try_from_set {
     ($t:ty) => {
-        //Ideally this would be handled in conversion.rs but because the GValue::Set holds a Vec
-        //we handle converting it here
-        impl FromGValue for HashSet<$t> {
-            fn from_gvalue(value: GValue) -> GremlinResult<Self> {
-                match value {
-                    GValue::List(s) => for_list_to_set(&s),
-                    GValue::Set(s) => for_set(&s),
-                    GValue::Null => Ok(HashSet::new()),
-                    _ => Err(GremlinError::Cast(format!(
-                        "Cannot cast {:?} to HashSet",
-                        value
-                    ))),
-                }
-            }
-        }
-
         impl std::convert::TryFrom<GValue> for HashSet<$t> {
             type Error = crate::GremlinError;
 
@@ -537,6 +698,11 @@ impl_try_from_set!(i64);
 impl_try_from_set!(Date);
 impl_try_from_set!(uuid::Uuid);
 impl_try_from_set!(bool);
+impl_try_from_set!(u8);
+impl_try_from_set!(u16);
+impl_try_from_set!(u32);
+impl_try_from_set!(u64);
+impl_try_from_set!(usize);
 //floats do not conform to the Eq or Hash traits
 // impl_try_from_set!(f32);
 // impl_try_from_set!(f64);
@@ -583,3 +749,129 @@ impl_try_from_list!(f64);
 impl_try_from_list!(Date);
 impl_try_from_list!(uuid::Uuid);
 impl_try_from_list!(bool);
+impl_try_from_list!(u8);
+impl_try_from_list!(u16);
+impl_try_from_list!(u32);
+impl_try_from_list!(u64);
+impl_try_from_list!(usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::io::GraphSON;
+    use crate::process::traversal::{Barrier, Bytecode, Merge, Operator, Order, Pick, Scope, DT};
+    use crate::structure::{
+        Branch, Cardinality, Column, GValue, Map, Path, Tree, VertexProperty, P, T,
+    };
+    use crate::{edge, vertex};
+    use std::collections::HashMap;
+
+    #[test]
+    fn display_like_the_console() {
+        let v: GValue = vertex!({ id => 1, label => "person", properties => {}}).into();
+        assert_eq!("v[1]", v.to_string());
+
+        let e: GValue = edge!({
+            id => 7,
+            label => "knows",
+            inV => { id => 2, label => "person" },
+            outV => { id => 1, label => "person" },
+            properties => {}
+        })
+        .into();
+        assert_eq!("e[7][1-knows->2]", e.to_string());
+
+        let vp: GValue = VertexProperty::new(0i64, "name", "marko").into();
+        assert_eq!("vp[name->marko]", vp.to_string());
+
+        let path = Path::builder()
+            .step(Vec::<String>::new(), v.clone())
+            .step(Vec::<String>::new(), e.clone())
+            .build();
+        assert_eq!(
+            "path[v[1], e[7][1-knows->2]]",
+            GValue::from(path).to_string()
+        );
+
+        let mut m = HashMap::new();
+        m.insert(String::from("name"), vec!["marko".into()].into());
+        m.insert(String::from("age"), vec![29.into()].into());
+        assert_eq!(
+            "[age:[29], name:[marko]]",
+            GValue::from(Map::from(m)).to_string()
+        );
+        assert_eq!(
+            "[:]",
+            GValue::from(HashMap::<String, GValue>::new()).to_string()
+        );
+
+        let tree = Tree::new(vec![Branch::new(
+            v,
+            Tree::new(vec![Branch::new("josh", Tree::default())]),
+        )]);
+        assert_eq!("{v[1]={josh={}}}", GValue::from(tree).to_string());
+
+        assert_eq!(
+            "within([1, 2])",
+            GValue::from(P::within((1, 2))).to_string()
+        );
+        assert_eq!("0.5", GValue::from(0.5).to_string());
+        assert_eq!("1.0", GValue::from(1.0).to_string());
+    }
+
+    #[test]
+    fn display_bytecode() {
+        let mut code = Bytecode::new();
+        code.add_step(String::from("V"), vec![1.into()]);
+        code.add_step(String::from("out"), vec!["knows".into()]);
+
+        assert_eq!("[[], [V(1), out(knows)]]", GValue::from(code).to_string());
+    }
+
+    #[test]
+    fn display_tokens_like_graphson() {
+        let tokens: Vec<GValue> = vec![
+            T::Id.into(),
+            T::Key.into(),
+            T::Label.into(),
+            T::Value.into(),
+            Scope::Global.into(),
+            Scope::Local.into(),
+            Order::Asc.into(),
+            Order::Desc.into(),
+            Order::Shuffle.into(),
+            Cardinality::List.into(),
+            Cardinality::Single.into(),
+            Cardinality::Set.into(),
+            Merge::OnCreate.into(),
+            Merge::OnMatch.into(),
+            Merge::OutV.into(),
+            Merge::InV.into(),
+            DT::Second.into(),
+            DT::Minute.into(),
+            DT::Hour.into(),
+            DT::Day.into(),
+            Column::Keys.into(),
+            Column::Values.into(),
+            Operator::Sum.into(),
+            Operator::Minus.into(),
+            Operator::Mult.into(),
+            Operator::Div.into(),
+            Operator::Min.into(),
+            Operator::Max.into(),
+            Operator::Assign.into(),
+            Operator::And.into(),
+            Operator::Or.into(),
+            Operator::AddAll.into(),
+            Operator::SumLong.into(),
+            Barrier::NormSack.into(),
+            Pick::Any.into(),
+            Pick::None.into(),
+        ];
+
+        for token in tokens {
+            let json = GraphSON::V3.write(&token).unwrap();
+
+            assert_eq!(json["@value"], token.to_string());
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::structure::VertexProperty;
 use crate::structure::GID;
 use std::collections::hash_map::{IntoIter, Iter};
 use std::collections::HashMap;
+use std::fmt;
 use std::hash::Hasher;
 
 #[derive(Debug, Clone)]
@@ -12,11 +13,7 @@ pub struct Vertex {
 }
 
 impl Vertex {
-    pub(crate) fn new<T>(
-        id: GID,
-        label: T,
-        properties: HashMap<String, Vec<VertexProperty>>,
-    ) -> Vertex
+    pub fn new<T>(id: GID, label: T, properties: HashMap<String, Vec<VertexProperty>>) -> Vertex
     where
         T: Into<String>,
     {
@@ -27,6 +24,14 @@ impl Vertex {
         }
     }
 
+    pub fn builder<G, T>(id: G, label: T) -> VertexBuilder
+    where
+        G: Into<GID>,
+        T: Into<String>,
+    {
+        VertexBuilder(Vertex::new(id.into(), label, HashMap::new()))
+    }
+
     pub fn id(&self) -> &GID {
         &self.id
     }
@@ -42,6 +47,47 @@ impl Vertex {
     pub fn property(&self, key: &str) -> Option<&VertexProperty> {
         self.properties.get(key).and_then(|v| v.get(0))
     }
+
+    /// All the values of a multi-property, empty if the key is missing
+    pub fn properties(&self, key: &str) -> &[VertexProperty] {
+        self.properties
+            .get(key)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn property_mut(&mut self, key: &str) -> Option<&mut VertexProperty> {
+        self.properties.get_mut(key).and_then(|v| v.get_mut(0))
+    }
+
+    pub fn properties_mut(&mut self, key: &str) -> Option<&mut Vec<VertexProperty>> {
+        self.properties.get_mut(key)
+    }
+
+    /// Append a value to the multi-property named after the property label
+    pub fn add_property(&mut self, property: VertexProperty) {
+        self.properties
+            .entry(property.label().clone())
+            .or_default()
+            .push(property);
+    }
+
+    pub fn remove_property(&mut self, key: &str) -> Option<Vec<VertexProperty>> {
+        self.properties.remove(key)
+    }
+}
+
+pub struct VertexBuilder(Vertex);
+
+impl VertexBuilder {
+    pub fn property(mut self, property: VertexProperty) -> Self {
+        self.0.add_property(property);
+        self
+    }
+
+    pub fn build(self) -> Vertex {
+        self.0
+    }
 }
 
 impl IntoIterator for Vertex {
@@ -65,3 +111,52 @@ impl PartialEq for Vertex {
         &self.id == other.id()
     }
 }
+
+impl fmt::Display for Vertex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v[{}]", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::structure::{GValue, Vertex, VertexProperty};
+
+    #[test]
+    fn builder_with_multi_properties_test() {
+        let mut vertex = Vertex::builder(1, "person")
+            .property(VertexProperty::new(2, "name", "marko"))
+            .property(VertexProperty::new(3, "location", "san diego"))
+            .property(VertexProperty::new(4, "location", "santa fe"))
+            .build();
+
+        assert_eq!(
+            &GValue::from("marko"),
+            vertex.property("name").unwrap().value()
+        );
+
+        let locations: Vec<&GValue> = vertex
+            .properties("location")
+            .iter()
+            .map(|p| p.value())
+            .collect();
+
+        assert_eq!(
+            vec![&GValue::from("san diego"), &GValue::from("santa fe")],
+            locations
+        );
+        assert!(vertex.properties("age").is_empty());
+
+        vertex
+            .property_mut("name")
+            .unwrap()
+            .set_value("marko a. rodriguez");
+
+        assert_eq!(
+            &GValue::from("marko a. rodriguez"),
+            vertex.property("name").unwrap().value()
+        );
+        assert_eq!(2, vertex.remove_property("location").unwrap().len());
+        assert!(vertex.property("location").is_none());
+    }
+}
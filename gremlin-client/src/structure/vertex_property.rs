@@ -1,5 +1,7 @@
+use crate::structure::property::same_value;
 use crate::structure::{GValue, Property, GID};
 use crate::{GremlinError, GremlinResult};
+use std::fmt;
 
 use crate::conversion::{BorrowFromGValue, FromGValue};
 
@@ -56,7 +58,7 @@ impl FromGValue for GProperty {
         }
     }
 }
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct VertexProperty {
     label: String,
     id: GID,
@@ -85,6 +87,17 @@ impl VertexProperty {
         &self.value
     }
 
+    pub fn value_mut(&mut self) -> &mut GValue {
+        &mut self.value
+    }
+
+    pub fn set_value<GT>(&mut self, value: GT)
+    where
+        GT: Into<GValue>,
+    {
+        *self.value = value.into();
+    }
+
     pub fn take<T>(self) -> GremlinResult<T>
     where
         T: FromGValue,
@@ -102,3 +115,23 @@ impl VertexProperty {
         &self.label
     }
 }
+
+impl PartialEq for VertexProperty {
+    fn eq(&self, other: &VertexProperty) -> bool {
+        self.id == other.id && self.label == other.label && same_value(&self.value, &other.value)
+    }
+}
+
+impl std::cmp::Eq for VertexProperty {}
+
+impl std::hash::Hash for VertexProperty {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Display for VertexProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "vp[{}->{}]", self.label, self.value)
+    }
+}
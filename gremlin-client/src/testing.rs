@@ -0,0 +1,643 @@
+//! A scripted Gremlin Server for tests, enabled by the `testing` feature.
+//!
+//! [MockServer](struct.MockServer.html) listens on a local port and speaks the driver
+//! protocol: mime type prefixed requests, partial (206) responses, the SASL (407) challenge
+//! and error codes. Responses are canned and keyed on the script or on the bytecode of
+//! the request, so code using [GremlinClient](../struct.GremlinClient.html) can be tested
+//! without a running Gremlin Server.
+//!
+//! ```rust
+//! use gremlin_client::testing::{MockResponse, MockServer};
+//! use gremlin_client::GremlinClient;
+//!
+//! fn main() -> Result<(), Box<std::error::Error>> {
+//!     let server = MockServer::start()?;
+//!     server.on_script("g.V().count()", MockResponse::values(vec![3i64.into()]));
+//!
+//!     let client = GremlinClient::connect(server.options().pool_size(1).build())?;
+//!
+//!     let count = client
+//!         .execute("g.V().count()", &[])?
+//!         .filter_map(Result::ok)
+//!         .map(|f| f.take::<i64>())
+//!         .collect::<Result<Vec<i64>, _>>()?;
+//!
+//!     assert_eq!(vec![3], count);
+//!     Ok(())
+//! }
+//! ```
+use crate::connection::ConnectionOptionsBuilder;
+use crate::process::traversal::Bytecode;
+use crate::{ConnectionOptions, GValue, GraphSON, GremlinError, GremlinResult, Map};
+use base64::decode;
+use serde_json::{json, Value};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use uuid::Uuid;
+use websocket::sync::{Client, Server};
+use websocket::OwnedMessage;
+
+/// A canned response, sent back when a request matches
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    code: i16,
+    message: String,
+    values: Vec<GValue>,
+    batch_size: Option<usize>,
+}
+
+impl MockResponse {
+    /// A successful response (200) with the given results
+    pub fn values(values: Vec<GValue>) -> MockResponse {
+        MockResponse {
+            code: 200,
+            message: String::default(),
+            values,
+            batch_size: None,
+        }
+    }
+
+    /// A successful response without results (204)
+    pub fn no_content() -> MockResponse {
+        MockResponse {
+            code: 204,
+            message: String::default(),
+            values: vec![],
+            batch_size: None,
+        }
+    }
+
+    /// A failed response, e.g. `597` for a script evaluation error
+    pub fn error<T>(code: i16, message: T) -> MockResponse
+    where
+        T: Into<String>,
+    {
+        MockResponse {
+            code,
+            message: message.into(),
+            values: vec![],
+            batch_size: None,
+        }
+    }
+
+    /// Stream the results in batches of `size`, every batch but the last one is sent as
+    /// partial content (206)
+    pub fn batch_size(mut self, size: usize) -> MockResponse {
+        self.batch_size = Some(size.max(1));
+        self
+    }
+}
+
+/// A request received by the [MockServer](struct.MockServer.html)
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    request_id: Uuid,
+    op: String,
+    processor: String,
+    args: Map,
+}
+
+impl MockRequest {
+    pub fn request_id(&self) -> &Uuid {
+        &self.request_id
+    }
+
+    pub fn op(&self) -> &str {
+        &self.op
+    }
+
+    pub fn processor(&self) -> &str {
+        &self.processor
+    }
+
+    pub fn args(&self) -> &Map {
+        &self.args
+    }
+
+    /// The script or the bytecode of the request
+    pub fn gremlin(&self) -> Option<&GValue> {
+        self.args.get("gremlin")
+    }
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Script(String),
+    Bytecode(Bytecode),
+}
+
+impl Matcher {
+    fn matches(&self, request: &MockRequest) -> bool {
+        match (self, request.gremlin()) {
+            (Matcher::Script(script), Some(GValue::String(s))) => script == s,
+            (Matcher::Bytecode(code), Some(GValue::Bytecode(b))) => code == b,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    responses: Vec<(Matcher, MockResponse)>,
+    fallback: Option<MockResponse>,
+    credentials: Option<(String, String)>,
+    requests: Vec<MockRequest>,
+}
+
+impl State {
+    fn response(&self, request: &MockRequest) -> MockResponse {
+        self.responses
+            .iter()
+            .find(|(matcher, _)| matcher.matches(request))
+            .map(|(_, response)| response.clone())
+            .or_else(|| self.fallback.clone())
+            .unwrap_or_else(|| {
+                MockResponse::error(597, format!("No mock response for {:?}", request.gremlin()))
+            })
+    }
+}
+
+/// A local websocket server answering Gremlin requests with canned responses.
+///
+/// The server runs on a background thread and is stopped when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    running: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    /// Start a server on a free local port
+    pub fn start() -> GremlinResult<MockServer> {
+        let mut server =
+            Server::bind("127.0.0.1:0").map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let addr = server
+            .local_addr()
+            .map_err(|e| GremlinError::Generic(e.to_string()))?;
+        let state = Arc::new(Mutex::new(State::default()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let accept_state = state.clone();
+        let accept_running = running.clone();
+        thread::spawn(move || {
+            while accept_running.load(Ordering::SeqCst) {
+                let client = match server.accept() {
+                    Ok(upgrade) => match upgrade.accept() {
+                        Ok(client) => client,
+                        Err(_) => continue,
+                    },
+                    Err(_) => continue,
+                };
+                if !accept_running.load(Ordering::SeqCst) {
+                    break;
+                }
+                let state = accept_state.clone();
+                thread::spawn(move || serve(client, state));
+            }
+        });
+
+        Ok(MockServer {
+            addr,
+            state,
+            running,
+        })
+    }
+
+    pub fn host(&self) -> String {
+        self.addr.ip().to_string()
+    }
+
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    /// Connection options pointing to this server
+    pub fn options(&self) -> ConnectionOptionsBuilder {
+        ConnectionOptions::builder()
+            .host(self.host())
+            .port(self.port())
+    }
+
+    /// Answer requests with the given script, bindings are ignored
+    pub fn on_script<T>(&self, script: T, response: MockResponse) -> &Self
+    where
+        T: Into<String>,
+    {
+        self.state()
+            .responses
+            .push((Matcher::Script(script.into()), response));
+        self
+    }
+
+    /// Answer traversals with the given bytecode
+    pub fn on_bytecode(&self, bytecode: &Bytecode, response: MockResponse) -> &Self {
+        self.state()
+            .responses
+            .push((Matcher::Bytecode(bytecode.clone()), response));
+        self
+    }
+
+    /// Answer the requests not matching any script or bytecode
+    pub fn otherwise(&self, response: MockResponse) -> &Self {
+        self.state().fallback = Some(response);
+        self
+    }
+
+    /// Challenge every new connection with a SASL authentication (407) and accept
+    /// only the given credentials
+    pub fn credentials(&self, username: &str, password: &str) -> &Self {
+        self.state().credentials = Some((String::from(username), String::from(password)));
+        self
+    }
+
+    /// The requests received so far, authentication requests excluded
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        // wake up the accepting thread
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+fn serve(mut client: Client<TcpStream>, state: Arc<Mutex<State>>) {
+    let mut authenticated = false;
+    let mut pending: Option<MockRequest> = None;
+
+    loop {
+        let payload = match client.recv_message() {
+            Ok(OwnedMessage::Binary(payload)) => payload,
+            Ok(OwnedMessage::Ping(data)) => {
+                let _ = client.send_message(&OwnedMessage::Pong(data));
+                continue;
+            }
+            Ok(OwnedMessage::Text(_)) | Ok(OwnedMessage::Pong(_)) => continue,
+            Ok(OwnedMessage::Close(_)) | Err(_) => return,
+        };
+
+        let (graphson, request) = match read_request(&payload) {
+            Ok(request) => request,
+            Err((request_id, e)) => {
+                let frame = response_frame(request_id, 499, &e, Value::Null);
+                if client.send_message(&frame).is_err() {
+                    return;
+                }
+                continue;
+            }
+        };
+
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        let credentials = state.credentials.clone();
+
+        let frames = match (&credentials, request.op.as_str()) {
+            (Some(credentials), "authentication") => match pending.take() {
+                Some(original) if check_sasl(&request, credentials) => {
+                    authenticated = true;
+                    response_frames(&graphson, &original, &state.response(&original))
+                }
+                _ => vec![response_frame(
+                    request.request_id,
+                    401,
+                    "Username and/or password are incorrect",
+                    Value::Null,
+                )],
+            },
+            (Some(_), _) if !authenticated => {
+                let id = request.request_id;
+                pending = Some(request.clone());
+                vec![response_frame(id, 407, "", Value::Null)]
+            }
+            _ => response_frames(&graphson, &request, &state.response(&request)),
+        };
+
+        if request.op != "authentication" {
+            state.requests.push(request);
+        }
+        drop(state);
+
+        for frame in frames {
+            if client.send_message(&frame).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+fn check_sasl(request: &MockRequest, (username, password): &(String, String)) -> bool {
+    let expected = format!("\0{}\0{}", username, password);
+    match request.args.get("sasl") {
+        Some(GValue::String(sasl)) => decode(sasl)
+            .map(|decoded| decoded == expected.as_bytes())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn read_request(payload: &[u8]) -> Result<(GraphSON, MockRequest), (Uuid, String)> {
+    let invalid = |message: &str| (Uuid::nil(), String::from(message));
+
+    let len = *payload.first().ok_or_else(|| invalid("Empty request"))? as usize;
+    let mime = payload
+        .get(1..=len)
+        .and_then(|m| std::str::from_utf8(m).ok())
+        .ok_or_else(|| invalid("Invalid mime type"))?;
+
+    let graphson = match mime {
+        "application/vnd.gremlin-v1.0+json" => GraphSON::V1,
+        "application/vnd.gremlin-v2.0+json" => GraphSON::V2,
+        "application/vnd.gremlin-v3.0+json" => GraphSON::V3,
+        _ => return Err(invalid(&format!("Unsupported mime type {}", mime))),
+    };
+
+    let message: Value =
+        serde_json::from_slice(&payload[len + 1..]).map_err(|e| (Uuid::nil(), e.to_string()))?;
+
+    let request_id = match &message["requestId"] {
+        Value::String(id) => Uuid::parse_str(id).ok(),
+        Value::Object(id) => id
+            .get("@value")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok()),
+        _ => None,
+    }
+    .ok_or_else(|| invalid("Invalid request id"))?;
+
+    let args = match graphson.read(&message["args"]) {
+        Ok(Some(GValue::Map(args))) => args,
+        Ok(_) => Map::from(std::collections::HashMap::<String, GValue>::new()),
+        Err(e) => return Err((request_id, e.to_string())),
+    };
+
+    let field = |name: &str| message[name].as_str().map(String::from).unwrap_or_default();
+
+    Ok((
+        graphson,
+        MockRequest {
+            request_id,
+            op: field("op"),
+            processor: field("processor"),
+            args,
+        },
+    ))
+}
+
+fn response_frames(
+    graphson: &GraphSON,
+    request: &MockRequest,
+    response: &MockResponse,
+) -> Vec<OwnedMessage> {
+    let id = request.request_id;
+    if response.code != 200 {
+        return vec![response_frame(
+            id,
+            response.code,
+            &response.message,
+            Value::Null,
+        )];
+    }
+
+    // traversal results are sent as traversers, as Gremlin Server does
+    let traversers = request.op == "bytecode";
+    let size = response.batch_size.unwrap_or(response.values.len());
+    let batches: Vec<&[GValue]> = if response.values.is_empty() || size >= response.values.len() {
+        vec![&response.values]
+    } else {
+        response.values.chunks(size).collect()
+    };
+
+    let data = match batches
+        .into_iter()
+        .map(|batch| write_results(graphson, batch, traversers))
+        .collect::<GremlinResult<Vec<Value>>>()
+    {
+        Ok(data) => data,
+        // Gremlin Server answers 599 when it can't serialize the results
+        Err(e) => {
+            let message = match e {
+                GremlinError::Generic(message) => message,
+                e => e.to_string(),
+            };
+            return vec![response_frame(id, 599, &message, Value::Null)];
+        }
+    };
+
+    let last = data.len() - 1;
+    data.into_iter()
+        .enumerate()
+        .map(|(i, data)| {
+            let code = if i == last { 200 } else { 206 };
+            response_frame(id, code, &response.message, data)
+        })
+        .collect()
+}
+
+fn write_results(graphson: &GraphSON, values: &[GValue], traversers: bool) -> GremlinResult<Value> {
+    let results = values
+        .iter()
+        .map(|v| graphson.write(v))
+        .map(|v| match v {
+            Ok(v) if traversers => Ok(json!({
+                "@type": "g:Traverser",
+                "@value": {
+                    "bulk": { "@type": "g:Int64", "@value": 1 },
+                    "value": v
+                }
+            })),
+            v => v,
+        })
+        .collect::<GremlinResult<Vec<Value>>>()?;
+
+    Ok(match graphson {
+        GraphSON::V3 => json!({ "@type": "g:List", "@value": results }),
+        _ => json!(results),
+    })
+}
+
+fn response_frame(request_id: Uuid, code: i16, message: &str, data: Value) -> OwnedMessage {
+    let response = json!({
+        "requestId": request_id,
+        "status": {
+            "code": code,
+            "message": message,
+            "attributes": {}
+        },
+        "result": {
+            "data": data,
+            "meta": {}
+        }
+    });
+
+    OwnedMessage::Binary(response.to_string().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockResponse, MockServer};
+    use crate::process::traversal::traversal;
+    use crate::structure::{GKey, Map, T};
+    use crate::{GValue, GraphSON, GremlinClient, GremlinError};
+    use std::collections::HashMap;
+
+    fn values(client: &GremlinClient, script: &str) -> Result<Vec<i64>, GremlinError> {
+        client
+            .execute(script, &[])?
+            .map(|r| r.and_then(|v| v.take::<i64>()))
+            .collect()
+    }
+
+    #[test]
+    fn mock_server_scripts() {
+        let server = MockServer::start().unwrap();
+        server
+            .on_script("g.V().count()", MockResponse::values(vec![6i64.into()]))
+            .on_script(
+                "g.V().values('age')",
+                MockResponse::values(vec![29i64.into(), 27i64.into(), 32i64.into()]).batch_size(2),
+            )
+            .on_script("g.V().drop()", MockResponse::no_content())
+            .on_script(
+                "g.fail()",
+                MockResponse::error(597, "No such property: fail"),
+            );
+
+        for serializer in [GraphSON::V1, GraphSON::V2, GraphSON::V3].iter() {
+            let client = GremlinClient::connect(
+                server
+                    .options()
+                    .pool_size(1)
+                    .serializer(serializer.clone())
+                    .deserializer(serializer.clone())
+                    .build(),
+            )
+            .unwrap();
+
+            assert_eq!(vec![6], values(&client, "g.V().count()").unwrap());
+            assert_eq!(
+                vec![29, 27, 32],
+                values(&client, "g.V().values('age')").unwrap()
+            );
+            assert!(values(&client, "g.V().drop()").unwrap().is_empty());
+
+            match values(&client, "g.fail()") {
+                Err(GremlinError::Request((597, message))) => {
+                    assert_eq!("No such property: fail", message)
+                }
+                other => panic!("Unexpected result {:?}", other),
+            }
+            match values(&client, "g.E()") {
+                Err(GremlinError::Request((597, _))) => {}
+                other => panic!("Unexpected result {:?}", other),
+            }
+        }
+
+        let requests = server.requests();
+        assert_eq!(15, requests.len());
+        assert_eq!("eval", requests[0].op());
+        assert_eq!(Some(&GValue::from("g.V().count()")), requests[0].gremlin());
+    }
+
+    #[test]
+    fn mock_server_bytecode() {
+        let server = MockServer::start().unwrap();
+        let client = GremlinClient::connect(server.options().pool_size(1).build()).unwrap();
+        let g = traversal().with_remote(client);
+
+        let names = g.v(()).has_label("person").values("name");
+        server
+            .on_bytecode(
+                names.bytecode(),
+                MockResponse::values(vec!["marko".into(), "vadas".into()]).batch_size(1),
+            )
+            .otherwise(MockResponse::values(vec![]));
+
+        assert_eq!(
+            vec![GValue::from("marko"), GValue::from("vadas")],
+            names.clone().to_list().unwrap()
+        );
+        assert!(g.e(()).to_list().unwrap().is_empty());
+
+        let requests = server.requests();
+        assert_eq!("bytecode", requests[0].op());
+        assert_eq!("traversal", requests[0].processor());
+    }
+
+    #[test]
+    fn mock_server_serialization_error() {
+        let server = MockServer::start().unwrap();
+        let mut labels = HashMap::new();
+        labels.insert(GKey::from(T::Label), GValue::from("person"));
+        server.on_script(
+            "g.V().group().by(label)",
+            MockResponse::values(vec![Map::from(labels).into()]),
+        );
+
+        let client = GremlinClient::connect(
+            server
+                .options()
+                .pool_size(1)
+                .serializer(GraphSON::V2)
+                .deserializer(GraphSON::V2)
+                .build(),
+        )
+        .unwrap();
+
+        let result = client
+            .execute("g.V().group().by(label)", &[])
+            .and_then(|results| results.collect::<Result<Vec<GValue>, _>>());
+        match result {
+            Err(GremlinError::Request((599, message))) => {
+                assert!(message.contains("requires GraphSON V3"))
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn mock_server_authentication() {
+        let server = MockServer::start().unwrap();
+        server
+            .credentials("stephen", "password")
+            .on_script("g.V().count()", MockResponse::values(vec![1i64.into()]));
+
+        let client = GremlinClient::connect(
+            server
+                .options()
+                .pool_size(1)
+                .credentials("stephen", "password")
+                .build(),
+        )
+        .unwrap();
+        assert_eq!(vec![1], values(&client, "g.V().count()").unwrap());
+        assert_eq!(vec![1], values(&client, "g.V().count()").unwrap());
+
+        let client = GremlinClient::connect(
+            server
+                .options()
+                .pool_size(1)
+                .credentials("stephen", "wrong")
+                .build(),
+        )
+        .unwrap();
+        match values(&client, "g.V().count()") {
+            Err(GremlinError::Request((401, _))) => {}
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        let client = GremlinClient::connect(server.options().pool_size(1).build()).unwrap();
+        match values(&client, "g.V().count()") {
+            Err(GremlinError::Request((407, _))) => {}
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! Checks of the derive macros output, they do not need a Gremlin Server.
+#![cfg(feature = "derive")]
+
+mod dsl {
+    use gremlin_client::derive::gremlin_dsl;
+    use gremlin_client::process::traversal::{traversal, Bytecode, TraversalBuilder, __};
+
+    #[gremlin_dsl]
+    pub trait Social {
+        fn persons(self) -> Self {
+            self.has_label("person")
+        }
+
+        fn knows(self, name: &str) -> Self {
+            self.out("knows").has(("name", name))
+        }
+    }
+
+    #[test]
+    fn test_dsl_steps() {
+        let g = traversal().empty();
+
+        let expected = TraversalBuilder::new(Bytecode::new())
+            .v(())
+            .has_label("person")
+            .out("knows")
+            .has(("name", "josh"));
+
+        assert_eq!(
+            expected.bytecode(),
+            g.v(()).persons().knows("josh").bytecode()
+        );
+        assert_eq!(expected.bytecode(), g.persons().knows("josh").bytecode());
+
+        let anonymous = TraversalBuilder::new(Bytecode::new()).has_label("person");
+
+        assert_eq!(anonymous.bytecode(), __.persons().bytecode());
+
+        let nested = TraversalBuilder::new(Bytecode::new())
+            .v(())
+            .where_(__.out("knows").has(("name", "josh")));
+
+        assert_eq!(
+            nested.bytecode(),
+            g.v(()).where_(__.knows("josh")).bytecode()
+        );
+    }
+}
+
+mod vertex_mapping {
+    use gremlin_client::derive::{GremlinVertex, ToGValue};
+    use gremlin_client::process::traversal::{
+        traversal, Bytecode, GremlinVertex, TraversalBuilder,
+    };
+    use gremlin_client::structure::{Cardinality, T};
+    use gremlin_client::{GKey, GValue, Map, ToGValue};
+    use std::collections::HashMap;
+
+    #[derive(GremlinVertex, ToGValue)]
+    #[gremlin(label = "person")]
+    struct Person {
+        #[gremlin(rename = "fullName")]
+        name: String,
+        age: i32,
+        #[gremlin(cardinality = "list")]
+        nicknames: Vec<String>,
+        email: Option<String>,
+        #[gremlin(skip)]
+        #[allow(dead_code)]
+        cache: Vec<u8>,
+    }
+
+    #[derive(GremlinVertex, ToGValue)]
+    struct Software {
+        #[gremlin(id)]
+        id: i64,
+        #[gremlin(label)]
+        kind: String,
+        name: String,
+    }
+
+    #[derive(GremlinVertex, ToGValue)]
+    #[gremlin(label = "tagged")]
+    struct Tagged<V> {
+        value: V,
+        tags: Option<V>,
+    }
+
+    fn person() -> Person {
+        Person {
+            name: String::from("marko"),
+            age: 29,
+            nicknames: vec![String::from("okram"), String::from("mr")],
+            email: None,
+            cache: vec![],
+        }
+    }
+
+    #[test]
+    fn test_create_and_lookup() {
+        let g = traversal().empty();
+
+        let expected = TraversalBuilder::new(Bytecode::new())
+            .add_v("person")
+            .property("fullName", "marko")
+            .property("age", 29)
+            .property_with_cardinality(Cardinality::List, "nicknames", "okram")
+            .property_with_cardinality(Cardinality::List, "nicknames", "mr");
+
+        assert_eq!(expected.bytecode(), person().create(&g).bytecode());
+
+        let expected = TraversalBuilder::new(Bytecode::new())
+            .v(())
+            .has_label("person")
+            .has(("fullName", "marko"))
+            .has(("age", 29))
+            .has(("nicknames", "okram"))
+            .has(("nicknames", "mr"));
+
+        assert_eq!(expected.bytecode(), person().lookup(&g).bytecode());
+    }
+
+    #[test]
+    fn test_create_and_lookup_with_id_and_label() {
+        let g = traversal().empty();
+
+        let software = Software {
+            id: 10,
+            kind: String::from("software"),
+            name: String::from("lop"),
+        };
+
+        let expected = TraversalBuilder::new(Bytecode::new())
+            .add_v("software")
+            .property(T::Id, 10i64)
+            .property("name", "lop");
+
+        assert_eq!(expected.bytecode(), software.create(&g).bytecode());
+
+        let expected = TraversalBuilder::new(Bytecode::new()).v(10i64);
+
+        assert_eq!(expected.bytecode(), software.lookup(&g).bytecode());
+    }
+
+    #[test]
+    fn test_to_gvalue() {
+        let mut expected: HashMap<GKey, GValue> = HashMap::new();
+        expected.insert("fullName".into(), "marko".into());
+        expected.insert("age".into(), 29.into());
+        expected.insert(
+            "nicknames".into(),
+            vec![GValue::from("okram"), GValue::from("mr")].into(),
+        );
+
+        assert_eq!(GValue::from(Map::from(expected)), person().to_gvalue());
+
+        let software = Software {
+            id: 10,
+            kind: String::from("software"),
+            name: String::from("lop"),
+        };
+
+        let mut expected: HashMap<GKey, GValue> = HashMap::new();
+        expected.insert(T::Id.into(), 10i64.into());
+        expected.insert(T::Label.into(), "software".into());
+        expected.insert("name".into(), "lop".into());
+
+        assert_eq!(GValue::from(Map::from(expected)), software.to_gvalue());
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        let g = traversal().empty();
+
+        let tagged = Tagged {
+            value: 1i64,
+            tags: None,
+        };
+
+        let expected = TraversalBuilder::new(Bytecode::new())
+            .add_v("tagged")
+            .property("value", 1i64);
+
+        assert_eq!(expected.bytecode(), tagged.create(&g).bytecode());
+
+        let mut expected: HashMap<GKey, GValue> = HashMap::new();
+        expected.insert("value".into(), 1i64.into());
+
+        assert_eq!(GValue::from(Map::from(expected)), tagged.to_gvalue());
+    }
+}
+
+mod value_mapping {
+    use gremlin_client::derive::{FromGMap, FromGValue};
+    use gremlin_client::{GKey, GValue, Map};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+
+    #[derive(Debug, PartialEq, FromGValue)]
+    enum Role {
+        Admin,
+        #[gremlin(rename = "guest")]
+        Guest,
+    }
+
+    #[derive(Debug, PartialEq, FromGValue, FromGMap)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug, PartialEq, FromGMap)]
+    struct Audit {
+        created: i64,
+    }
+
+    #[derive(Debug, PartialEq, FromGValue, FromGMap)]
+    struct Person {
+        #[gremlin(rename = "fullName")]
+        name: String,
+        age: Option<i32>,
+        #[gremlin(default)]
+        score: i64,
+        role: Role,
+        addresses: Vec<Address>,
+        home: Option<Address>,
+        #[gremlin(flatten)]
+        audit: Audit,
+        #[gremlin(skip)]
+        cache: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, FromGValue)]
+    struct Name(String);
+
+    #[derive(Debug, PartialEq, FromGValue)]
+    struct Pair(String, i32);
+
+    #[derive(Debug, PartialEq, FromGValue)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    fn map(entries: Vec<(&str, GValue)>) -> Map {
+        entries
+            .into_iter()
+            .map(|(k, v)| (GKey::from(k), v))
+            .collect::<HashMap<GKey, GValue>>()
+            .into()
+    }
+
+    fn address(city: &str) -> GValue {
+        map(vec![("city", city.into())]).into()
+    }
+
+    #[test]
+    fn test_from_gmap_attributes() {
+        let value = map(vec![
+            ("fullName", "marko".into()),
+            ("role", "guest".into()),
+            (
+                "addresses",
+                vec![address("rome"), address("santa fe")].into(),
+            ),
+            ("created", 10i64.into()),
+        ]);
+
+        let person = Person::try_from(value).unwrap();
+
+        assert_eq!(
+            Person {
+                name: String::from("marko"),
+                age: None,
+                score: 0,
+                role: Role::Guest,
+                addresses: vec![
+                    Address {
+                        city: String::from("rome")
+                    },
+                    Address {
+                        city: String::from("santa fe")
+                    }
+                ],
+                home: None,
+                audit: Audit { created: 10 },
+                cache: vec![],
+            },
+            person
+        );
+
+        let value = map(vec![
+            ("fullName", "vadas".into()),
+            ("age", 27.into()),
+            ("score", 5i64.into()),
+            ("role", "Admin".into()),
+            ("home", address("rome")),
+            ("created", 10i64.into()),
+        ]);
+
+        let person = Person::try_from(GValue::from(value)).unwrap();
+
+        assert_eq!(Some(27), person.age);
+        assert_eq!(5, person.score);
+        assert_eq!(Role::Admin, person.role);
+        assert_eq!(
+            Some(Address {
+                city: String::from("rome")
+            }),
+            person.home
+        );
+    }
+
+    #[test]
+    fn test_from_gvalue_errors() {
+        let missing = map(vec![("role", "guest".into()), ("created", 10i64.into())]);
+
+        assert_eq!(
+            "Cast error: Person.name: missing value",
+            Person::try_from(missing).unwrap_err().to_string()
+        );
+
+        let wrong_role = map(vec![
+            ("fullName", "marko".into()),
+            ("role", "owner".into()),
+            ("created", 10i64.into()),
+        ]);
+
+        assert_eq!(
+            "Cast error: Person.role: Unknown variant `owner` of Role",
+            Person::try_from(wrong_role).unwrap_err().to_string()
+        );
+
+        let nested = map(vec![
+            ("fullName", "marko".into()),
+            ("role", "guest".into()),
+            ("home", map(vec![("city", 1.into())]).into()),
+            ("created", 10i64.into()),
+        ]);
+
+        assert!(Person::try_from(nested)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Cast error: Person.home: Address.city: "));
+    }
+
+    #[test]
+    fn test_from_gvalue_tuple_and_generic_structs() {
+        assert_eq!(
+            Name(String::from("marko")),
+            Name::try_from(GValue::from("marko")).unwrap()
+        );
+
+        assert_eq!(
+            Pair(String::from("marko"), 29),
+            Pair::try_from(GValue::from(vec![GValue::from("marko"), GValue::from(29)])).unwrap()
+        );
+
+        assert!(Pair::try_from(GValue::from(vec![GValue::from("marko")])).is_err());
+
+        let wrapper: Wrapper<i64> =
+            Wrapper::try_from(GValue::from(map(vec![("value", 1i64.into())]))).unwrap();
+
+        assert_eq!(Wrapper { value: 1 }, wrapper);
+    }
+}
@@ -386,6 +386,8 @@ fn test_group_count_vertex_v1() {
         GID::String(s) => s.to_string(),
         GID::Int32(i) => i.to_string(),
         GID::Int64(i) => i.to_string(),
+        GID::Uuid(u) => u.to_string(),
+        GID::Custom(_) => unreachable!(),
     }));
 
     assert_eq!(Some(&GValue::Int64(1)), count);
@@ -421,6 +423,8 @@ fn test_group_count_edge_v1() {
         GID::String(s) => s.to_string(),
         GID::Int32(i) => i.to_string(),
         GID::Int64(i) => i.to_string(),
+        GID::Uuid(u) => u.to_string(),
+        GID::Custom(_) => unreachable!(),
     }));
 
     assert_eq!(Some(&GValue::Int64(1)), count);
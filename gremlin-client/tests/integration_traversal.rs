@@ -1,6 +1,8 @@
-use gremlin_client::process::traversal::{traversal, Order, __};
+use gremlin_client::process::traversal::{traversal, Order, __, IO};
 use gremlin_client::structure::{Cardinality, List, Map, Pop, TextP, Vertex, VertexProperty, P, T};
 use gremlin_client::utils;
+use gremlin_client::{ConnectionOptions, GValue, GremlinClient};
+use std::collections::HashMap;
 
 mod common;
 
@@ -810,6 +812,12 @@ fn test_group_count_step() {
     let value = &results[0];
 
     assert_eq!(&1, value["test_group_count"].get::<i64>().unwrap());
+
+    let counts = GValue::from(value.clone())
+        .take::<HashMap<String, i64>>()
+        .unwrap();
+
+    assert_eq!(Some(&1), counts.get("test_group_count"));
 }
 
 #[test]
@@ -974,6 +982,45 @@ fn test_path_step() {
     assert_eq!(v.id(), value.objects()[0].get::<Vertex>().unwrap().id());
 }
 
+#[test]
+fn test_tree_step() {
+    let client = graph();
+
+    drop_vertices(&client, "test_tree_step").unwrap();
+
+    let v1 = create_vertex_with_label(&client, "test_tree_step", "a");
+    let v2 = create_vertex_with_label(&client, "test_tree_step", "b");
+    let v3 = create_vertex_with_label(&client, "test_tree_step", "c");
+
+    create_edge(&client, &v1, &v2, "TreeStepEdge");
+    create_edge(&client, &v2, &v3, "TreeStepEdge");
+
+    let g = traversal().with_remote(client);
+
+    let tree = g
+        .v(v1.id())
+        .out("TreeStepEdge")
+        .out("TreeStepEdge")
+        .tree()
+        .by("name")
+        .next()
+        .unwrap()
+        .expect("It should return a tree");
+
+    assert_eq!(
+        vec![&GValue::from("a")],
+        tree.children().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![&GValue::from("c")], tree.leaves());
+
+    let depths = tree
+        .depth_first()
+        .map(|(depth, _)| depth)
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![0, 1, 2], depths);
+}
+
 #[test]
 fn test_limit_step() {
     let client = graph();
@@ -1364,6 +1411,143 @@ fn match_step_test() {
     assert_eq!(&v3[0], first["c"].get::<Vertex>().unwrap());
 }
 
+#[test]
+fn test_next_does_not_drain_result_set() {
+    let client = GremlinClient::connect(
+        ConnectionOptions::builder()
+            .host("localhost")
+            .port(8182)
+            .pool_size(1)
+            .build(),
+    )
+    .unwrap();
+
+    drop_vertices(&client, "test_next_does_not_drain").unwrap();
+
+    let g = traversal().with_remote(client);
+
+    for i in 0..100 {
+        g.add_v("test_next_does_not_drain")
+            .property("index", i)
+            .next()
+            .unwrap();
+    }
+
+    let first = g
+        .v(())
+        .has_label("test_next_does_not_drain")
+        .values("index")
+        .order(())
+        .next()
+        .unwrap();
+
+    assert_eq!(Some(0), first.map(|v| v.take::<i32>().unwrap()));
+
+    assert_eq!(
+        true,
+        g.v(())
+            .has_label("test_next_does_not_drain")
+            .has_next()
+            .unwrap()
+    );
+
+    // the abandoned partial responses must not leak into later requests
+    let count = g
+        .v(())
+        .has_label("test_next_does_not_drain")
+        .count()
+        .next()
+        .unwrap();
+
+    assert_eq!(Some(100), count);
+}
+
+#[test]
+fn test_barrier_expands_bulk() {
+    let client = graph();
+
+    drop_vertices(&client, "test_barrier_expands_bulk").unwrap();
+
+    let hub = create_vertex_with_label(&client, "test_barrier_expands_bulk", "hub");
+    let a = create_vertex_with_label(&client, "test_barrier_expands_bulk", "a");
+    let b = create_vertex_with_label(&client, "test_barrier_expands_bulk", "b");
+
+    create_edge(&client, &a, &hub, "TestBarrierExpandsBulk");
+    create_edge(&client, &b, &hub, "TestBarrierExpandsBulk");
+
+    let g = traversal().with_remote(client);
+
+    let results = g
+        .v(vec![a.id(), b.id()])
+        .out("TestBarrierExpandsBulk")
+        .barrier()
+        .to_list()
+        .unwrap();
+
+    assert_eq!(2, results.len());
+    assert_eq!(hub.id(), results[0].id());
+    assert_eq!(hub.id(), results[1].id());
+}
+
+#[test]
+fn test_io_read() {
+    let g = traversal().with_remote(graph());
+
+    // the kitchen sink sample shipped in the data directory of the Gremlin Server image
+    g.v(vec![1000i64, 2000, 2001]).drop().to_list().unwrap();
+
+    let results = g
+        .io("data/tinkerpop-sink.xml")
+        .with_((IO::READER, IO::GRAPHML))
+        .read()
+        .to_list()
+        .unwrap();
+
+    assert_eq!(0, results.len());
+
+    let names = g
+        .v(())
+        .has_label("message")
+        .values("name")
+        .order(())
+        .to_list()
+        .unwrap();
+
+    assert_eq!(vec![GValue::from("a"), GValue::from("b")], names);
+}
+
+#[test]
+fn test_profile() {
+    let client = graph();
+
+    drop_vertices(&client, "test_profile").unwrap();
+
+    create_vertex_with_label(&client, "test_profile", "a");
+
+    let g = traversal().with_remote(client);
+
+    let metrics = g
+        .v(())
+        .has_label("test_profile")
+        .profile()
+        .next()
+        .unwrap()
+        .expect("It should return the traversal metrics");
+
+    assert!(metrics.metrics().len() > 0);
+    assert!(format!("{}", metrics).contains(">TOTAL"));
+}
+
+#[test]
+fn test_explain() {
+    let g = traversal().with_remote(graph());
+
+    let explanation = g.v(()).has_label("test_explain").explain().unwrap();
+
+    assert_eq!(2, explanation.original().len());
+    assert!(explanation.final_t().len() > 0);
+}
+
 #[test]
 fn drop_step_test() {
     let client = graph();
@@ -1919,6 +2103,7 @@ fn test_coalesce_unfold() {
 fn test_traversal_vertex_mapping() {
     use chrono::{DateTime, TimeZone, Utc};
     use gremlin_client::derive::FromGMap;
+    use gremlin_client::process::traversal::{Bytecode, TraversalBuilder};
     use std::convert::TryFrom;
 
     let client = graph();
@@ -791,6 +791,8 @@ fn test_group_count_step_v2() {
                 GID::String(s) => s.to_string(),
                 GID::Int32(i) => i.to_string(),
                 GID::Int64(i) => i.to_string(),
+                GID::Uuid(u) => u.to_string(),
+                GID::Custom(_) => unreachable!(),
             }))
             .unwrap()
     );
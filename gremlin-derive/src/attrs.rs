@@ -0,0 +1,156 @@
+use syn::{Attribute, Field, GenericArgument, Lit, Meta, NestedMeta, PathArguments, Type};
+
+pub enum Cardinality {
+    List,
+    Set,
+    Single,
+}
+
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub cardinality: Option<Cardinality>,
+    pub id: bool,
+    pub label: bool,
+    pub default: bool,
+    pub flatten: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(field: &Field) -> syn::Result<FieldAttrs> {
+        let attrs = FieldAttrs::from_attrs(&field.attrs)?;
+
+        if attrs.id && attrs.label {
+            return Err(syn::Error::new_spanned(
+                field,
+                "A field cannot be both the id and the label",
+            ));
+        }
+        Ok(attrs)
+    }
+
+    pub fn from_attrs(attributes: &[Attribute]) -> syn::Result<FieldAttrs> {
+        let mut attrs = FieldAttrs::default();
+
+        for meta in gremlin_metas(attributes)? {
+            match &meta {
+                Meta::Path(path) if path.is_ident("skip") => attrs.skip = true,
+                Meta::Path(path) if path.is_ident("default") => attrs.default = true,
+                Meta::Path(path) if path.is_ident("flatten") => attrs.flatten = true,
+                Meta::Path(path) if path.is_ident("id") => attrs.id = true,
+                Meta::Path(path) if path.is_ident("label") => attrs.label = true,
+                Meta::NameValue(nv) if nv.path.is_ident("rename") => {
+                    attrs.rename = Some(lit_str(&nv.lit)?);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("cardinality") => {
+                    attrs.cardinality = Some(match lit_str(&nv.lit)?.as_str() {
+                        "list" => Cardinality::List,
+                        "set" => Cardinality::Set,
+                        "single" => Cardinality::Single,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                &nv.lit,
+                                "cardinality must be one of \"list\", \"set\" or \"single\"",
+                            ))
+                        }
+                    });
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "Unknown gremlin field attribute",
+                    ))
+                }
+            }
+        }
+        Ok(attrs)
+    }
+
+    /// The property key of the field, its name unless renamed
+    pub fn key(&self, field: &Field) -> String {
+        self.rename.clone().unwrap_or_else(|| {
+            field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// `#[gremlin(label = "...")]` on the struct itself
+pub fn container_label(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut label = None;
+
+    for meta in gremlin_metas(attrs)? {
+        match &meta {
+            Meta::NameValue(nv) if nv.path.is_ident("label") => label = Some(lit_str(&nv.lit)?),
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "Unknown gremlin container attribute",
+                ))
+            }
+        }
+    }
+    Ok(label)
+}
+
+fn gremlin_metas(attrs: &[Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("gremlin")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => metas.push(meta),
+                        NestedMeta::Lit(lit) => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "Expected a gremlin attribute, found a literal",
+                            ))
+                        }
+                    }
+                }
+            }
+            other => return Err(syn::Error::new_spanned(other, "Expected #[gremlin(...)]")),
+        }
+    }
+    Ok(metas)
+}
+
+fn lit_str(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        other => Err(syn::Error::new_spanned(other, "Expected a string literal")),
+    }
+}
+
+/// Whether the last path segment of the type is `name`, e.g. `Option` or `Vec`
+pub fn is_type(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == name)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// The first generic argument of the type, e.g. `T` in `Option<T>`
+pub fn inner_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) => match &path.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,191 @@
+use quote::{format_ident, quote};
+use syn::{FnArg, ItemTrait, Pat, ReturnType, TraitItem, TraitItemMethod, Type};
+
+pub fn expand(input: &ItemTrait) -> syn::Result<proc_macro2::TokenStream> {
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let source_ident = format_ident!("{}Source", ident);
+    let anonymous_ident = format_ident!("{}Anonymous", ident);
+
+    let methods = input
+        .items
+        .iter()
+        .map(|item| match item {
+            TraitItem::Method(method) => validate(method).map(|_| method),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "Only methods are supported in a gremlin_dsl trait",
+            )),
+        })
+        .collect::<syn::Result<Vec<&TraitItemMethod>>>()?;
+
+    let mut declarations = vec![];
+    let mut builder_impls = vec![];
+    let mut traversal_impls = vec![];
+    let mut source_declarations = vec![];
+    let mut source_impls = vec![];
+    let mut anonymous_declarations = vec![];
+    let mut anonymous_impls = vec![];
+
+    for method in methods {
+        let attrs = &method.attrs;
+        let sig = &method.sig;
+        let name = &sig.ident;
+        let generics = &sig.generics;
+        let where_clause = &sig.generics.where_clause;
+        let body = method.default.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(sig, "gremlin_dsl methods need a default implementation")
+        })?;
+
+        let params: Vec<&syn::PatType> = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat) => Some(pat),
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+        let args: Vec<&syn::Ident> = params
+            .iter()
+            .filter_map(|p| match &*p.pat {
+                Pat::Ident(pat) => Some(&pat.ident),
+                _ => None,
+            })
+            .collect();
+
+        declarations.push(quote! {
+            #(#attrs)*
+            #sig;
+        });
+
+        builder_impls.push(quote! {
+            #sig #body
+        });
+
+        traversal_impls.push(quote! {
+            #sig {
+                self.with_builder(|builder| {
+                    <gremlin_client::process::traversal::TraversalBuilder as #ident>::#name(builder, #(#args),*)
+                })
+            }
+        });
+
+        source_declarations.push(quote! {
+            #(#attrs)*
+            fn #name #generics(&self, #(#params),*) -> gremlin_client::process::traversal::GraphTraversal<gremlin_client::Vertex, gremlin_client::Vertex, DslTerminator> #where_clause;
+        });
+
+        source_impls.push(quote! {
+            fn #name #generics(&self, #(#params),*) -> gremlin_client::process::traversal::GraphTraversal<gremlin_client::Vertex, gremlin_client::Vertex, DslTerminator> #where_clause {
+                #ident::#name(self.v(()), #(#args),*)
+            }
+        });
+
+        anonymous_declarations.push(quote! {
+            #(#attrs)*
+            fn #name #generics(&self, #(#params),*) -> gremlin_client::process::traversal::TraversalBuilder #where_clause;
+        });
+
+        anonymous_impls.push(quote! {
+            fn #name #generics(&self, #(#params),*) -> gremlin_client::process::traversal::TraversalBuilder #where_clause {
+                <gremlin_client::process::traversal::TraversalBuilder as #ident>::#name(
+                    gremlin_client::process::traversal::TraversalBuilder::default(),
+                    #(#args),*
+                )
+            }
+        });
+    }
+
+    let attrs = &input.attrs;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis trait #ident: Sized {
+            #(#declarations)*
+        }
+
+        impl #ident for gremlin_client::process::traversal::TraversalBuilder {
+            #(#builder_impls)*
+        }
+
+        impl<S, E, T> #ident for gremlin_client::process::traversal::GraphTraversal<S, E, T>
+        where
+            E: gremlin_client::FromGValue,
+            T: gremlin_client::process::traversal::Terminator<E>,
+        {
+            #(#traversal_impls)*
+        }
+
+        #vis trait #source_ident<DslTerminator>
+        where
+            DslTerminator: gremlin_client::process::traversal::Terminator<gremlin_client::GValue>
+                + gremlin_client::process::traversal::Terminator<gremlin_client::Vertex>,
+        {
+            #(#source_declarations)*
+        }
+
+        impl<DslTerminator> #source_ident<DslTerminator> for gremlin_client::process::traversal::GraphTraversalSource<DslTerminator>
+        where
+            DslTerminator: gremlin_client::process::traversal::Terminator<gremlin_client::GValue>
+                + gremlin_client::process::traversal::Terminator<gremlin_client::Vertex>,
+        {
+            #(#source_impls)*
+        }
+
+        #vis trait #anonymous_ident {
+            #(#anonymous_declarations)*
+        }
+
+        impl #anonymous_ident for gremlin_client::process::traversal::AnonymousTraversalSource {
+            #(#anonymous_impls)*
+        }
+    })
+}
+
+// Every step must take `self` by value, return `Self` and bind its arguments to
+// plain identifiers so they can be forwarded to the builder implementation.
+fn validate(method: &TraitItemMethod) -> syn::Result<()> {
+    let sig = &method.sig;
+
+    match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_none() => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "gremlin_dsl methods must take `self` by value",
+            ))
+        }
+    }
+
+    match &sig.output {
+        ReturnType::Type(_, ty) if is_self(ty) => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "gremlin_dsl methods must return `Self`",
+            ))
+        }
+    }
+
+    for arg in sig.inputs.iter().skip(1) {
+        if let FnArg::Typed(pat) = arg {
+            match &*pat.pat {
+                Pat::Ident(_) => {}
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "gremlin_dsl method arguments must be plain identifiers",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_self(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("Self"),
+        _ => false,
+    }
+}
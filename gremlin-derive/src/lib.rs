@@ -1,8 +1,11 @@
 use proc_macro::TokenStream;
 
+mod attrs;
+mod dsl;
 mod value;
+mod vertex;
 
-#[proc_macro_derive(FromGValue)]
+#[proc_macro_derive(FromGValue, attributes(gremlin))]
 pub fn derive_from_gvalue(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
@@ -12,7 +15,7 @@ pub fn derive_from_gvalue(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(FromGMap)]
+#[proc_macro_derive(FromGMap, attributes(gremlin))]
 pub fn derive_from_gmap(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
 
@@ -21,3 +24,39 @@ pub fn derive_from_gmap(input: TokenStream) -> TokenStream {
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+#[proc_macro_derive(ToGValue, attributes(gremlin))]
+pub fn derive_to_gvalue(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match value::derive_to_gvalue(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(GremlinVertex, attributes(gremlin))]
+pub fn derive_gremlin_vertex(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match vertex::derive(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Turns a trait of domain steps into extension methods on `GraphTraversal`, `TraversalBuilder`,
+/// `GraphTraversalSource` (`<Trait>Source`) and `__` (`<Trait>Anonymous`).
+///
+/// The source methods always start the traversal with `V()`, e.g. `g.persons()` is
+/// `g.V().persons()`; start from another step with `g.e(()).persons()`. The generated code refers
+/// to the client as `gremlin_client`, so the crate must not be renamed in `Cargo.toml`.
+#[proc_macro_attribute]
+pub fn gremlin_dsl(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::ItemTrait);
+
+    match dsl::expand(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -1,46 +1,145 @@
+use crate::attrs::{inner_type, is_type, FieldAttrs};
 use quote::quote;
-use syn::{parse_quote, Data, DataStruct, DeriveInput, Fields, FieldsNamed, Stmt};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{
+    parse_quote, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, FieldsNamed,
+    FieldsUnnamed, Generics, Type, TypeParamBound,
+};
 
 pub fn derive(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
-    match &input.data {
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let generics = with_bounds(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let context = context(&name);
+
+    let body = match &input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(FieldsNamed { named, .. }),
             ..
         }) => {
-            let ident = &input.ident;
-
-            let (m_reads, m_names) = map_properties(named);
-
-            Ok(quote! {
+            let construct = read_named(&name, named)?;
 
-                impl std::convert::TryFrom<gremlin_client::GValue> for #ident {
-                    type Error = gremlin_client::GremlinError;
-                    fn try_from(result : gremlin_client::GValue) -> gremlin_client::GremlinResult<Self>{
+            quote! {
+                match value {
+                    gremlin_client::GValue::Map(__gremlin_map) => {
+                        #construct
+                    }
+                    gremlin_client::GValue::List(list) if list.len() == 1 => {
+                        Self::try_from(list.iter().cloned().next().unwrap_or(gremlin_client::GValue::Null))
+                    }
+                    other => Err(gremlin_client::GremlinError::Cast(format!(
+                        "Cannot convert {:?} to {}",
+                        other, #name
+                    ))),
+                }
+            }
+        }
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(FieldsUnnamed { unnamed, .. }),
+            ..
+        }) if unnamed.len() == 1 => {
+            let read = read_field(&name, "0", &unnamed[0], quote!(Some(value)))?;
 
+            quote! {
+                #context
+                Ok(#ident(#read))
+            }
+        }
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(FieldsUnnamed { unnamed, .. }),
+            ..
+        }) => {
+            let len = unnamed.len();
+            let reads = unnamed
+                .iter()
+                .enumerate()
+                .map(|(idx, field)| {
+                    read_field(
+                        &name,
+                        &idx.to_string(),
+                        field,
+                        quote!(__gremlin_values.next()),
+                    )
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
 
-                        match result {
-                            gremlin_client::GValue::Map(map) => {
-                                #(#m_reads)*
+            quote! {
+                match value {
+                    gremlin_client::GValue::List(list) if list.len() == #len => {
+                        #context
+                        let mut __gremlin_values = list.iter().cloned();
+                        Ok(#ident(#(#reads),*))
+                    }
+                    other => Err(gremlin_client::GremlinError::Cast(format!(
+                        "Cannot convert {:?} to {}, expected a list of {} elements",
+                        other, #name, #len
+                    ))),
+                }
+            }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut arms = vec![];
 
-                                Ok(#ident {
-                                    #(#m_names),*
-                                })
-                            }
-                             _ => {
-                                Err(gremlin_client::GremlinError::Cast(String::from("Cannot")))
-                            }
-                        }
+            for variant in variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "Only unit variants are supported for FromGValue derive",
+                    ));
+                }
+                let attrs = FieldAttrs::from_attrs(&variant.attrs)?;
+                let variant_ident = &variant.ident;
+                let key = attrs.rename.unwrap_or_else(|| variant_ident.to_string());
 
+                arms.push(quote!(#key => Ok(#ident::#variant_ident),));
+            }
 
+            quote! {
+                match value {
+                    gremlin_client::GValue::String(s) => match s.as_str() {
+                        #(#arms)*
+                        other => Err(gremlin_client::GremlinError::Cast(format!(
+                            "Unknown variant `{}` of {}",
+                            other, #name
+                        ))),
+                    },
+                    gremlin_client::GValue::List(list) if list.len() == 1 => {
+                        Self::try_from(list.iter().cloned().next().unwrap_or(gremlin_client::GValue::Null))
                     }
+                    other => Err(gremlin_client::GremlinError::Cast(format!(
+                        "Cannot convert {:?} to {}",
+                        other, #name
+                    ))),
                 }
-            })
+            }
         }
-        _ => Err(syn::Error::new_spanned(
-            input,
-            "Only structs are supported for Repo derive",
-        )),
-    }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Only structs and enums with unit variants are supported for FromGValue derive",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics std::convert::TryFrom<gremlin_client::GValue> for #ident #ty_generics #where_clause {
+            type Error = gremlin_client::GremlinError;
+
+            fn try_from(value: gremlin_client::GValue) -> gremlin_client::GremlinResult<Self> {
+                #[allow(unused_imports)]
+                use std::convert::TryFrom;
+                #body
+            }
+        }
+
+        impl #impl_generics gremlin_client::FromGValue for #ident #ty_generics #where_clause {
+            fn from_gvalue(value: gremlin_client::GValue) -> gremlin_client::GremlinResult<Self> {
+                <Self as std::convert::TryFrom<gremlin_client::GValue>>::try_from(value)
+            }
+        }
+    })
 }
 
 pub fn derive_map(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
@@ -50,47 +149,249 @@ pub fn derive_map(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream>
             ..
         }) => {
             let ident = &input.ident;
+            let generics = with_bounds(&input.generics);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-            let (m_reads, m_names) = map_properties(named);
+            let construct = read_named(&ident.to_string(), named)?;
 
             Ok(quote! {
-
-                impl std::convert::TryFrom<gremlin_client::Map> for #ident {
+                impl #impl_generics std::convert::TryFrom<gremlin_client::Map> for #ident #ty_generics #where_clause {
                     type Error = gremlin_client::GremlinError;
-                    fn try_from(map : gremlin_client::Map) -> gremlin_client::GremlinResult<Self>{
-                        #(#m_reads)*
 
-                        Ok(#ident {
-                            #(#m_names),*
-                        })
+                    fn try_from(__gremlin_map: gremlin_client::Map) -> gremlin_client::GremlinResult<Self> {
+                        #construct
                     }
                 }
             })
         }
         _ => Err(syn::Error::new_spanned(
             input,
-            "Only structs are supported for Repo derive",
+            "Only structs with named fields are supported for FromGMap derive",
         )),
     }
 }
 
-fn map_properties(
-    named: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
-) -> (Vec<Stmt>, Vec<&Option<proc_macro2::Ident>>) {
-    let reads = named
-        .iter()
-        .filter_map(|field| -> Option<Stmt> {
-            let id = &field.ident.as_ref()?;
-            let id_s = id.to_string();
-            let ty = &field.ty;
-
-            Some(parse_quote!(
-                let #id: #ty = map.try_get(#id_s)?;
-            ))
+/// Every type parameter has to be readable from a `GValue`
+fn with_bounds(generics: &Generics) -> Generics {
+    bounded(
+        generics,
+        parse_quote!(std::convert::TryFrom<gremlin_client::GValue, Error = gremlin_client::GremlinError>),
+    )
+}
+
+/// Adds `bound` to every type parameter of `generics`
+pub(crate) fn bounded(generics: &Generics, bound: TypeParamBound) -> Generics {
+    let mut generics = generics.clone();
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+/// Reads the named fields out of `__gremlin_map` and builds `Self`
+fn read_named(
+    name: &str,
+    named: &Punctuated<Field, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut fields = vec![];
+
+    for field in named {
+        let attrs = FieldAttrs::from_attrs(&field.attrs)?;
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let field_name = ident
+            .as_ref()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+
+        let read = if attrs.skip {
+            quote!(Default::default())
+        } else if attrs.flatten {
+            quote! {
+                <#ty as std::convert::TryFrom<gremlin_client::Map>>::try_from(__gremlin_map.clone())
+                    .map_err(|e| __gremlin_context(#field_name, e))?
+            }
+        } else {
+            let key = attrs.key(field);
+            read_field(
+                name,
+                &field_name,
+                field,
+                quote!(__gremlin_map.get(#key).cloned()),
+            )?
+        };
+
+        fields.push(quote!(#ident: #read));
+    }
+
+    let context = context(name);
+
+    Ok(quote! {
+        #context
+        Ok(Self {
+            #(#fields),*
         })
-        .collect();
+    })
+}
 
-    let names = named.iter().map(|field| &field.ident).collect();
+/// Prefixes a conversion error with the struct and the field that failed
+fn context(name: &str) -> proc_macro2::TokenStream {
+    quote! {
+        #[allow(unused_variables)]
+        let __gremlin_context = |field: &str, e: gremlin_client::GremlinError| {
+            gremlin_client::GremlinError::Cast(match e {
+                gremlin_client::GremlinError::Cast(msg) => format!("{}.{}: {}", #name, field, msg),
+                other => format!("{}.{}: {}", #name, field, other),
+            })
+        };
+    }
+}
+
+/// Reads one field from `source`, an `Option<GValue>` that is `None` when the key is missing
+fn read_field(
+    name: &str,
+    field_name: &str,
+    field: &Field,
+    source: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attrs = FieldAttrs::from_attrs(&field.attrs)?;
+    let convert = convert(&field.ty);
+
+    let convert = if attrs.default {
+        quote! {
+            match __gremlin_value {
+                None | Some(gremlin_client::GValue::Null) => Ok(Default::default()),
+                __gremlin_value => #convert,
+            }
+        }
+    } else {
+        convert
+    };
 
-    (reads, names)
+    Ok(quote! {{
+        let __gremlin_value: Option<gremlin_client::GValue> = #source;
+        let __gremlin_missing = __gremlin_value.is_none();
+        let __gremlin_result: gremlin_client::GremlinResult<_> = #convert;
+        __gremlin_result.map_err(|e| {
+            if __gremlin_missing {
+                gremlin_client::GremlinError::Cast(format!("{}.{}: missing value", #name, #field_name))
+            } else {
+                __gremlin_context(#field_name, e)
+            }
+        })?
+    }})
+}
+
+/// Converts `__gremlin_value` into the type, `Option` and `Vec` are unwrapped so that
+/// they work with any element type readable from a `GValue`
+fn convert(ty: &Type) -> proc_macro2::TokenStream {
+    match inner_type(ty) {
+        Some(inner) if is_type(ty, "Option") => quote! {
+            match __gremlin_value {
+                None | Some(gremlin_client::GValue::Null) => Ok(None),
+                Some(value) => <#inner as std::convert::TryFrom<gremlin_client::GValue>>::try_from(value).map(Some),
+            }
+        },
+        Some(inner) if is_type(ty, "Vec") => quote! {
+            match __gremlin_value {
+                None | Some(gremlin_client::GValue::Null) => Ok(Vec::new()),
+                Some(gremlin_client::GValue::List(list)) => list
+                    .iter()
+                    .cloned()
+                    .map(<#inner as std::convert::TryFrom<gremlin_client::GValue>>::try_from)
+                    .collect(),
+                Some(gremlin_client::GValue::Set(set)) => set
+                    .iter()
+                    .cloned()
+                    .map(<#inner as std::convert::TryFrom<gremlin_client::GValue>>::try_from)
+                    .collect(),
+                Some(other) => Err(gremlin_client::GremlinError::Cast(format!(
+                    "Cannot convert {:?} to a list",
+                    other
+                ))),
+            }
+        },
+        _ => quote! {
+            <#ty as std::convert::TryFrom<gremlin_client::GValue>>::try_from(
+                __gremlin_value.unwrap_or(gremlin_client::GValue::Null),
+            )
+        },
+    }
+}
+
+pub fn derive_to_gvalue(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(FieldsNamed { named, .. }),
+            ..
+        }) => {
+            let ident = &input.ident;
+            let generics = bounded(&input.generics, parse_quote!(gremlin_client::ToGValue));
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            let mut inserts = vec![];
+
+            for field in named {
+                let attrs = FieldAttrs::parse(field)?;
+                let name = &field.ident;
+
+                if attrs.skip {
+                    continue;
+                }
+                if attrs.flatten {
+                    inserts.push(quote! {
+                        if let gremlin_client::GValue::Map(nested) = gremlin_client::ToGValue::to_gvalue(&self.#name) {
+                            for (key, value) in nested.iter() {
+                                map.insert(key.clone(), value.clone());
+                            }
+                        }
+                    });
+                    continue;
+                }
+
+                let key = if attrs.id {
+                    quote!(gremlin_client::structure::T::Id.into())
+                } else if attrs.label {
+                    quote!(gremlin_client::structure::T::Label.into())
+                } else {
+                    let key = attrs.key(field);
+                    quote!(#key.into())
+                };
+
+                // Missing optional values are left out rather than written as null
+                inserts.push(if is_type(&field.ty, "Option") {
+                    quote! {
+                        if let Some(value) = &self.#name {
+                            map.insert(#key, gremlin_client::ToGValue::to_gvalue(value));
+                        }
+                    }
+                } else {
+                    quote! {
+                        map.insert(#key, gremlin_client::ToGValue::to_gvalue(&self.#name));
+                    }
+                });
+            }
+
+            Ok(quote! {
+                impl #impl_generics gremlin_client::ToGValue for #ident #ty_generics #where_clause {
+                    fn to_gvalue(&self) -> gremlin_client::GValue {
+                        #[allow(unused_mut)]
+                        let mut map = std::collections::HashMap::<gremlin_client::GKey, gremlin_client::GValue>::new();
+                        #(#inserts)*
+                        gremlin_client::GValue::from(map)
+                    }
+                }
+            })
+        }
+        _ => Err(syn::Error::new_spanned(
+            input,
+            "Only structs are supported for ToGValue derive",
+        )),
+    }
 }
@@ -0,0 +1,157 @@
+use crate::attrs::{container_label, is_type, Cardinality, FieldAttrs};
+use crate::value::bounded;
+use quote::quote;
+use syn::{parse_quote, Data, DataStruct, DeriveInput, Fields, FieldsNamed};
+
+pub fn derive(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let named = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(FieldsNamed { named, .. }),
+            ..
+        }) => named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Only structs with named fields are supported for GremlinVertex derive",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let generics = bounded(&input.generics, parse_quote!(gremlin_client::ToGValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut label = container_label(&input.attrs)?
+        .map(|label| quote!(#label))
+        .unwrap_or_else(|| {
+            let label = ident.to_string();
+            quote!(#label)
+        });
+    let mut id = None;
+    let mut writes = vec![];
+    let mut lookups = vec![];
+
+    for field in named {
+        let attrs = FieldAttrs::parse(field)?;
+        let name = &field.ident;
+
+        if attrs.skip {
+            continue;
+        }
+        if attrs.flatten {
+            return Err(syn::Error::new_spanned(
+                field,
+                "flatten is not supported for GremlinVertex derive",
+            ));
+        }
+        if attrs.id {
+            id = Some(quote!(self.#name));
+            continue;
+        }
+        if attrs.label {
+            label = quote!(std::convert::AsRef::<str>::as_ref(&self.#name));
+            continue;
+        }
+
+        let key = attrs.key(field);
+        let is_option = is_type(&field.ty, "Option");
+        let is_vec = is_type(&field.ty, "Vec");
+
+        let (write, lookup) = match &attrs.cardinality {
+            Some(cardinality) => {
+                let cardinality = match cardinality {
+                    Cardinality::List => quote!(gremlin_client::structure::Cardinality::List),
+                    Cardinality::Set => quote!(gremlin_client::structure::Cardinality::Set),
+                    Cardinality::Single => quote!(gremlin_client::structure::Cardinality::Single),
+                };
+                (
+                    quote! {
+                        traversal = traversal.property_with_cardinality(
+                            #cardinality,
+                            #key,
+                            gremlin_client::ToGValue::to_gvalue(value),
+                        );
+                    },
+                    quote! {
+                        traversal = traversal.has((#key, gremlin_client::ToGValue::to_gvalue(value)));
+                    },
+                )
+            }
+            None => (
+                quote! {
+                    traversal = traversal.property(#key, gremlin_client::ToGValue::to_gvalue(value));
+                },
+                quote! {
+                    traversal = traversal.has((#key, gremlin_client::ToGValue::to_gvalue(value)));
+                },
+            ),
+        };
+
+        // A multi-property is written and matched one value at a time
+        let each = |body: proc_macro2::TokenStream| {
+            if attrs.cardinality.is_some() && is_vec {
+                quote!(for value in &self.#name { #body })
+            } else if is_option {
+                quote!(if let Some(value) = &self.#name { #body })
+            } else {
+                quote!({ let value = &self.#name; #body })
+            }
+        };
+
+        writes.push(each(write));
+        lookups.push(each(lookup));
+    }
+
+    let (id_write, lookup_body) = match id {
+        Some(id) => (
+            quote! {
+                let id = gremlin_client::ToGValue::to_gvalue(&#id);
+                traversal = traversal.with_builder(|builder| {
+                    builder.property(gremlin_client::structure::T::Id, id)
+                });
+            },
+            quote! {
+                g.v(#id.clone())
+            },
+        ),
+        None => (
+            quote!(),
+            quote! {
+                #[allow(unused_mut)]
+                let mut traversal = g.v(()).has_label(#label);
+                #(#lookups)*
+                traversal
+            },
+        ),
+    };
+
+    Ok(quote! {
+        impl #impl_generics gremlin_client::process::traversal::GremlinVertex for #ident #ty_generics #where_clause {
+            fn create<A>(
+                &self,
+                g: &gremlin_client::process::traversal::GraphTraversalSource<A>,
+            ) -> gremlin_client::process::traversal::GraphTraversal<gremlin_client::Vertex, gremlin_client::Vertex, A>
+            where
+                A: gremlin_client::process::traversal::Terminator<gremlin_client::GValue>
+                    + gremlin_client::process::traversal::Terminator<gremlin_client::Vertex>,
+            {
+                #[allow(unused_mut)]
+                let mut traversal = g.add_v(#label);
+                #id_write
+                #(#writes)*
+                traversal
+            }
+
+            fn lookup<A>(
+                &self,
+                g: &gremlin_client::process::traversal::GraphTraversalSource<A>,
+            ) -> gremlin_client::process::traversal::GraphTraversal<gremlin_client::Vertex, gremlin_client::Vertex, A>
+            where
+                A: gremlin_client::process::traversal::Terminator<gremlin_client::GValue>
+                    + gremlin_client::process::traversal::Terminator<gremlin_client::Vertex>,
+            {
+                #lookup_body
+            }
+        }
+    })
+}